@@ -4,6 +4,8 @@
 use std::{
     collections::HashMap,
     fmt::Debug,
+    future::Future,
+    hash::Hash,
     ops::Deref,
     str::FromStr,
     sync::Arc,
@@ -42,6 +44,8 @@ pub enum MetadataBuilderError {
     UnsupportedModuleType(ModuleType),
     #[error("Exceeded max depth when building metadata ({0})")]
     MaxDepthExceeded(u32),
+    #[error("Routing ISM has no module configured for origin domain ({0})")]
+    NoRouteConfigured(u32),
 }
 
 #[derive(Debug)]
@@ -57,53 +61,129 @@ pub trait MetadataBuilder: Send + Sync {
         -> Result<Option<Vec<u8>>>;
 }
 
+/// Caches values fetched from onchain for a period of time to avoid fetching
+/// them all the time. Holds at most `max_entries` values, dropping expired and
+/// then the oldest values to make room for new ones.
+#[derive(Debug)]
+pub struct TtlCache<K, V> {
+    values: RwLock<HashMap<K, (V, Instant)>>,
+    ttl: Duration,
+    max_entries: usize,
+}
+
+impl<K: Eq + Hash + Copy, V: Copy> TtlCache<K, V> {
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            values: RwLock::new(HashMap::new()),
+            ttl,
+            max_entries: max_entries.max(1),
+        }
+    }
+
+    /// Gets the value of `key`, calling `fetch` if there is no cached value
+    /// or it is stale.
+    pub async fn get_or_fetch<F, Fut>(&self, key: K, fetch: F) -> Result<V>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V>>,
+    {
+        // In its own block to avoid holding the lock while fetching the value
+        {
+            let values = self.values.read().await;
+
+            if let Some((value, fetched_at)) = values.get(&key) {
+                if fetched_at.elapsed() < self.ttl {
+                    return Ok(*value);
+                }
+            }
+        }
+
+        let value = fetch().await?;
+        let mut values = self.values.write().await;
+        if values.len() >= self.max_entries && !values.contains_key(&key) {
+            values.retain(|_, (_, fetched_at)| fetched_at.elapsed() < self.ttl);
+        }
+        if values.len() >= self.max_entries && !values.contains_key(&key) {
+            let oldest = values
+                .iter()
+                .min_by_key(|(_, (_, fetched_at))| *fetched_at)
+                .map(|(key, _)| *key);
+            if let Some(oldest) = oldest {
+                values.remove(&oldest);
+            }
+        }
+        values.insert(key, (value, Instant::now()));
+
+        Ok(value)
+    }
+
+    /// Drops the cached value of `key`, so the next lookup fetches it again.
+    pub async fn invalidate(&self, key: &K) {
+        self.values.write().await.remove(key);
+    }
+}
+
+/// Time to live for cached ISM addresses. 10 mins.
+const ISM_CACHE_TTL: Duration = Duration::from_secs(60 * 10);
+
 /// Allows fetching the default ISM, caching the value for a period of time
 /// to avoid fetching it all the time.
-/// TODO: make this generic
 #[derive(Debug)]
 pub struct DefaultIsmCache {
-    value: RwLock<Option<(H256, Instant)>>,
+    cache: TtlCache<(), H256>,
     mailbox: Arc<dyn Mailbox>,
 }
 
 impl DefaultIsmCache {
-    /// Time to live for the cached default ISM. 10 mins.
-    const TTL: Duration = Duration::from_secs(60 * 10);
-
     pub fn new(mailbox: Arc<dyn Mailbox>) -> Self {
         Self {
-            value: RwLock::new(None),
+            cache: TtlCache::new(ISM_CACHE_TTL, 1),
             mailbox,
         }
     }
 
     /// Gets the default ISM, fetching it from onchain if the cached value
     /// is stale.
-    /// TODO: this can and should be made generic eventually
     pub async fn get(&self) -> Result<H256> {
-        // If the duration since the value was last updated does not
-        // exceed the TTL, return the cached value.
-        // This is in its own block to avoid holding the lock during the
-        // async operation to fetch the on-chain default ISM if
-        // the cached value is stale.
-        {
-            let value = self.value.read().await;
+        self.cache
+            .get_or_fetch((), || async move { Ok(self.mailbox.default_ism().await?) })
+            .await
+    }
+}
 
-            if let Some(value) = *value {
-                if value.1.elapsed() < Self::TTL {
-                    return Ok(value.0);
-                }
-            }
-        }
+/// Caches the ISM of each recipient on a destination mailbox for a period of
+/// time, since it rarely changes and is otherwise fetched for every message.
+#[derive(Debug)]
+pub struct RecipientIsmCache {
+    cache: TtlCache<H256, H256>,
+    mailbox: Arc<dyn Mailbox>,
+}
 
-        let default_ism = self.mailbox.default_ism().await?;
-        // Update the cached value.
-        {
-            let mut value = self.value.write().await;
-            *value = Some((default_ism, Instant::now()));
+impl RecipientIsmCache {
+    /// Maximum number of recipients whose ISM is cached.
+    const MAX_ENTRIES: usize = 10_000;
+
+    pub fn new(mailbox: Arc<dyn Mailbox>) -> Self {
+        Self {
+            cache: TtlCache::new(ISM_CACHE_TTL, Self::MAX_ENTRIES),
+            mailbox,
         }
+    }
+
+    /// Gets the ISM of the recipient, fetching it from onchain if there is
+    /// no cached value or it is stale.
+    pub async fn get(&self, recipient: H256) -> Result<H256> {
+        self.cache
+            .get_or_fetch(recipient, || async move {
+                Ok(self.mailbox.recipient_ism(recipient).await?)
+            })
+            .await
+    }
 
-        Ok(default_ism)
+    /// Drops the cached ISM of the recipient, e.g. after delivery failed
+    /// because the recipient may have changed its ISM.
+    pub async fn invalidate(&self, recipient: H256) {
+        self.cache.invalidate(&recipient).await;
     }
 }
 
@@ -435,3 +515,89 @@ impl BaseMetadataBuilder {
         ))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use hyperlane_test::mocks::MockMailboxContract;
+
+    use super::*;
+
+    fn mock_mailbox(expected_fetches: usize) -> Arc<dyn Mailbox> {
+        let mut mailbox = MockMailboxContract::new();
+        mailbox
+            .expect__recipient_ism()
+            .times(expected_fetches)
+            .returning(|recipient| Ok(H256::from_low_u64_be(recipient.to_low_u64_be() + 100)));
+        Arc::new(mailbox)
+    }
+
+    #[tokio::test]
+    async fn test_recipient_ism_cache_hit_and_miss() {
+        // One fetch per distinct recipient, the repeated lookup is a hit
+        let cache = RecipientIsmCache::new(mock_mailbox(2));
+        let (recipient_1, recipient_2) = (H256::from_low_u64_be(1), H256::from_low_u64_be(2));
+
+        assert_eq!(
+            cache.get(recipient_1).await.unwrap(),
+            H256::from_low_u64_be(101)
+        );
+        assert_eq!(
+            cache.get(recipient_1).await.unwrap(),
+            H256::from_low_u64_be(101)
+        );
+        assert_eq!(
+            cache.get(recipient_2).await.unwrap(),
+            H256::from_low_u64_be(102)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_recipient_ism_cache_invalidate() {
+        let cache = RecipientIsmCache::new(mock_mailbox(2));
+        let recipient = H256::from_low_u64_be(1);
+
+        cache.get(recipient).await.unwrap();
+        cache.invalidate(recipient).await;
+        // The invalidated value is refetched
+        assert_eq!(
+            cache.get(recipient).await.unwrap(),
+            H256::from_low_u64_be(101)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ttl_cache_expires() {
+        let cache = TtlCache::new(Duration::from_millis(10), 10);
+        let fetches = &AtomicUsize::new(0);
+        let fetch = move || async move {
+            fetches.fetch_add(1, Ordering::SeqCst);
+            Ok(H256::from_low_u64_be(101))
+        };
+
+        cache.get_or_fetch(1u32, fetch).await.unwrap();
+        cache.get_or_fetch(1u32, fetch).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        // The stale value is refetched
+        cache.get_or_fetch(1u32, fetch).await.unwrap();
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_ttl_cache_is_bounded() {
+        let cache = TtlCache::new(Duration::from_secs(60), 2);
+        for key in 0u32..3 {
+            cache
+                .get_or_fetch(key, || async move { Ok(key) })
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+
+        let values = cache.values.read().await;
+        assert_eq!(values.len(), 2);
+        // The oldest value made room for the newest one
+        assert!(!values.contains_key(&0));
+    }
+}
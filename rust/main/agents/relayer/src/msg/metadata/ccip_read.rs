@@ -55,45 +55,149 @@ impl MetadataBuilder for CcipReadIsmMetadataBuilder {
             }
         };
 
-        for url in info.urls.iter() {
-            // Need to explicitly convert the sender H160 the hex because the `ToString` implementation
-            // for `H160` truncates the output. (e.g. `0xc66a…7b6f` instead of returning
-            // the full address)
-            let sender_as_bytes = &bytes_to_hex(info.sender.as_bytes());
-            let data_as_bytes = &info.call_data.to_string();
-            let interpolated_url = url
-                .replace("{sender}", sender_as_bytes)
-                .replace("{data}", data_as_bytes);
-            let res = if !url.contains("{data}") {
-                let body = json!({
-                    "sender": sender_as_bytes,
-                    "data": data_as_bytes
-                });
-                Client::new()
-                    .post(interpolated_url)
-                    .header("Content-Type", "application/json")
-                    .json(&body)
-                    .send()
-                    .await?
-            } else {
-                reqwest::get(interpolated_url).await?
-            };
-
-            let json: Result<OffchainResponse, reqwest::Error> = res.json().await;
-
-            match json {
-                Ok(result) => {
-                    // remove leading 0x which hex_decode doesn't like
-                    let metadata = hex_decode(&result.data[2..])?;
-                    return Ok(Some(metadata));
-                }
-                Err(_err) => {
-                    // try the next URL
+        // Need to explicitly convert the sender H160 the hex because the `ToString` implementation
+        // for `H160` truncates the output. (e.g. `0xc66a…7b6f` instead of returning
+        // the full address)
+        let sender = bytes_to_hex(info.sender.as_bytes());
+        let data = info.call_data.to_string();
+        Ok(fetch_offchain_data(&info.urls, &sender, &data).await)
+    }
+}
+
+/// Substitutes the `{sender}` and `{data}` parameters of an EIP-3668 gateway url.
+fn interpolate_url(url: &str, sender: &str, data: &str) -> String {
+    url.replace("{sender}", sender).replace("{data}", data)
+}
+
+/// Queries the EIP-3668 gateways in order, returning the data from the first
+/// one that responds successfully. Gateways that fail or respond with
+/// unexpected data are skipped.
+async fn fetch_offchain_data(urls: &[String], sender: &str, data: &str) -> Option<Vec<u8>> {
+    let client = Client::new();
+    for url in urls.iter() {
+        let interpolated_url = interpolate_url(url, sender, data);
+        // Gateways without a `{data}` parameter expect the request as a POST body
+        let request = if !url.contains("{data}") {
+            let body = json!({
+                "sender": sender,
+                "data": data
+            });
+            client
+                .post(interpolated_url)
+                .header("Content-Type", "application/json")
+                .json(&body)
+        } else {
+            client.get(interpolated_url)
+        };
+
+        let res = match request.send().await {
+            Ok(res) => res,
+            Err(err) => {
+                info!(?err, %url, "CcipRead gateway request failed, trying the next url");
+                continue;
+            }
+        };
+
+        match res.json::<OffchainResponse>().await {
+            // remove leading 0x which hex_decode doesn't like
+            Ok(result) => match hex_decode(result.data.trim_start_matches("0x")) {
+                Ok(metadata) => return Some(metadata),
+                Err(err) => {
+                    info!(?err, %url, "Invalid CcipRead gateway response, trying the next url")
                 }
+            },
+            Err(err) => {
+                info!(?err, %url, "Invalid CcipRead gateway response, trying the next url")
             }
         }
+    }
+
+    // No metadata endpoints or endpoints down
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::SocketAddr;
+
+    use axum::{
+        extract::Path,
+        http::StatusCode,
+        routing::{get, post},
+        Json, Router,
+    };
+
+    use super::*;
+
+    const SENDER: &str = "0xc66aaab4e6b3f9b1e3c1d2e1ba8d6e0e1c817b6f";
+
+    fn spawn_gateways() -> SocketAddr {
+        let app = Router::new()
+            .route(
+                "/down",
+                post(|| async { StatusCode::INTERNAL_SERVER_ERROR }),
+            )
+            .route(
+                "/get/:sender/:data",
+                get(|Path((_, data)): Path<(String, String)>| async move {
+                    Json(OffchainResponse { data })
+                }),
+            )
+            .route(
+                "/post",
+                post(|Json(body): Json<serde_json::Value>| async move {
+                    Json(OffchainResponse {
+                        data: body["data"].as_str().unwrap().to_owned(),
+                    })
+                }),
+            );
+
+        // Running the app in the background using a test server
+        let server =
+            axum::Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(app.into_make_service());
+        let addr = server.local_addr();
+        tokio::spawn(server);
+        addr
+    }
+
+    #[test]
+    fn test_interpolate_url() {
+        assert_eq!(
+            interpolate_url("https://gateway.xyz/{sender}/{data}.json", SENDER, "0x1234"),
+            format!("https://gateway.xyz/{SENDER}/0x1234.json")
+        );
+        assert_eq!(
+            interpolate_url("https://gateway.xyz/lookup", SENDER, "0x1234"),
+            "https://gateway.xyz/lookup"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_offchain_data_falls_back_to_next_gateway() {
+        let addr = spawn_gateways();
+        let urls = vec![
+            format!("http://{addr}/down"),
+            format!("http://{addr}/get/{{sender}}/{{data}}"),
+        ];
+
+        let metadata = fetch_offchain_data(&urls, SENDER, "0xdeadbeef").await;
+        assert_eq!(metadata, Some(vec![0xde, 0xad, 0xbe, 0xef]));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_offchain_data_posts_without_data_param() {
+        let addr = spawn_gateways();
+        let urls = vec![format!("http://{addr}/post")];
+
+        let metadata = fetch_offchain_data(&urls, SENDER, "0xdeadbeef").await;
+        assert_eq!(metadata, Some(vec![0xde, 0xad, 0xbe, 0xef]));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_offchain_data_all_gateways_down() {
+        let addr = spawn_gateways();
+        let urls = vec![format!("http://{addr}/down"), format!("http://{addr}/down")];
 
-        // No metadata endpoints or endpoints down
-        Ok(None)
+        assert_eq!(fetch_offchain_data(&urls, SENDER, "0xdeadbeef").await, None);
     }
 }
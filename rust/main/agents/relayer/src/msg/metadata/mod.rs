@@ -8,7 +8,8 @@ mod routing;
 use aggregation::AggregationIsmMetadataBuilder;
 pub(crate) use base::MetadataBuilder;
 pub(crate) use base::{
-    AppContextClassifier, BaseMetadataBuilder, IsmAwareAppContextClassifier, MessageMetadataBuilder,
+    AppContextClassifier, BaseMetadataBuilder, IsmAwareAppContextClassifier,
+    MessageMetadataBuilder, RecipientIsmCache,
 };
 use ccip_read::CcipReadIsmMetadataBuilder;
 use null_metadata::NullMetadataBuilder;
@@ -10,7 +10,7 @@ use hyperlane_base::MultisigCheckpointSyncer;
 use hyperlane_core::accumulator::merkle::Proof;
 use hyperlane_core::{HyperlaneMessage, MultisigSignedCheckpoint, H256};
 use strum::Display;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 use crate::msg::metadata::base::MessageMetadataBuilder;
 
@@ -121,6 +121,17 @@ impl<T: MultisigIsmMetadataBuilder> MetadataBuilder for T {
             .await
             .context(CTX)?;
 
+        // Validators that can't be read from, e.g. because a rotated key was
+        // never announced, can never contribute to a quorum
+        let unreachable_validators = checkpoint_syncer.validators_without_syncer(&validators);
+        if validators.len() - unreachable_validators.len() < usize::from(threshold) {
+            warn!(
+                hyp_message=?message, ?unreachable_validators, threshold, ism=%multisig_ism.address(),
+                "Could not fetch metadata: Too few validators have a usable announced storage location to reach quorum"
+            );
+            return Ok(None);
+        }
+
         if let Some(metadata) = self
             .fetch_metadata(&validators, threshold, message, &checkpoint_syncer)
             .await
@@ -5,7 +5,7 @@ use eyre::Context;
 use hyperlane_core::{HyperlaneMessage, H256};
 use tracing::instrument;
 
-use super::{MessageMetadataBuilder, MetadataBuilder};
+use super::{base::MetadataBuilderError, MessageMetadataBuilder, MetadataBuilder};
 
 #[derive(Clone, Debug, new, Deref)]
 pub struct RoutingIsmMetadataBuilder {
@@ -24,6 +24,9 @@ impl MetadataBuilder for RoutingIsmMetadataBuilder {
         const CTX: &str = "When fetching RoutingIsm metadata";
         let ism = self.build_routing_ism(ism_address).await.context(CTX)?;
         let module = ism.route(message).await.context(CTX)?;
+        if module.is_zero() {
+            return Err(MetadataBuilderError::NoRouteConfigured(message.origin)).context(CTX);
+        }
         self.base.build(module, message).await.context(CTX)
     }
 }
@@ -25,7 +25,7 @@ use tracing::{debug, error, info, info_span, instrument, trace, warn, Instrument
 
 use super::{
     gas_payment::{GasPaymentEnforcer, GasPolicyStatus},
-    metadata::{BaseMetadataBuilder, MessageMetadataBuilder, MetadataBuilder},
+    metadata::{BaseMetadataBuilder, MessageMetadataBuilder, MetadataBuilder, RecipientIsmCache},
 };
 
 pub const CONFIRM_DELAY: Duration = if cfg!(any(test, feature = "test-utils")) {
@@ -36,11 +36,25 @@ pub const CONFIRM_DELAY: Duration = if cfg!(any(test, feature = "test-utils")) {
     Duration::from_secs(60 * 10)
 };
 
+/// Revert reason of the mailbox when the recipient's ISM rejects a message.
+const ISM_VERIFICATION_FAILED: &str = "Mailbox: ISM verification failed";
+
+/// Whether delivering a message failed because the recipient's ISM rejected
+/// it. Depending on the provider the revert reason is either in plain text or
+/// ABI-encoded in the revert data.
+fn is_ism_revert(err: &impl ToString) -> bool {
+    let err = err.to_string();
+    err.contains(ISM_VERIFICATION_FAILED)
+        || err.contains(&ethers::utils::hex::encode(ISM_VERIFICATION_FAILED))
+}
+
 /// The message context contains the links needed to submit a message. Each
 /// instance is for a unique origin -> destination pairing.
 pub struct MessageContext {
     /// Mailbox on the destination chain.
     pub destination_mailbox: Arc<dyn Mailbox>,
+    /// Caches the ISMs of recipients on the destination mailbox.
+    pub recipient_ism_cache: Arc<RecipientIsmCache>,
     /// Origin chain database to verify gas payments.
     pub origin_db: HyperlaneRocksDB,
     /// Used to construct the ISM metadata needed to verify a message from the
@@ -239,8 +253,8 @@ impl PendingOperation for PendingMessage {
 
         let ism_address = match self
             .ctx
-            .destination_mailbox
-            .recipient_ism(self.message.recipient)
+            .recipient_ism_cache
+            .get(self.message.recipient)
             .await
         {
             Ok(ism_address) => ism_address,
@@ -289,6 +303,7 @@ impl PendingOperation for PendingMessage {
         {
             Ok(tx_cost_estimate) => tx_cost_estimate,
             Err(err) => {
+                self.on_delivery_error(&err).await;
                 return self.on_reprepare(Some(err), ReprepareReason::ErrorEstimatingGas);
             }
         };
@@ -352,14 +367,14 @@ impl PendingOperation for PendingMessage {
 
         // To avoid spending gas on a tx that will revert, dry-run just before submitting.
         if let Some(metadata) = self.metadata.as_ref() {
-            if self
+            if let Err(err) = self
                 .ctx
                 .destination_mailbox
                 .process_estimate_costs(&self.message, metadata)
                 .await
-                .is_err()
             {
-                return self.on_reprepare::<String>(None, ReprepareReason::ErrorEstimatingGas);
+                self.on_delivery_error(&err).await;
+                return self.on_reprepare(Some(err), ReprepareReason::ErrorEstimatingGas);
             }
         }
 
@@ -377,6 +392,7 @@ impl PendingOperation for PendingMessage {
             }
             Err(e) => {
                 error!(error=?e, "Error when processing message");
+                self.on_delivery_error(&e).await;
                 return PendingOperationResult::Reprepare(ReprepareReason::ErrorSubmitting);
             }
         }
@@ -550,6 +566,21 @@ impl PendingMessage {
         PendingOperationResult::Reprepare(reason)
     }
 
+    /// Drops the cached ISM of the recipient if its ISM rejected the message,
+    /// since the recipient may have changed its ISM since it was cached.
+    async fn on_delivery_error(&self, err: &ChainCommunicationError) {
+        if is_ism_revert(err) {
+            debug!(
+                recipient=?self.message.recipient,
+                "Recipient ISM rejected the message, invalidating its cached ISM"
+            );
+            self.ctx
+                .recipient_ism_cache
+                .invalidate(self.message.recipient)
+                .await;
+        }
+    }
+
     fn on_reconfirm<E: Debug>(&mut self, err: Option<E>, reason: &str) -> PendingOperationResult {
         self.inc_attempts();
         if let Some(e) = err {
@@ -673,3 +704,26 @@ impl MessageSubmissionMetrics {
             .set(std::cmp::max(self.last_known_nonce.get(), msg.nonce as i64));
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_ism_revert() {
+        assert!(is_ism_revert(
+            &"(code: 3, message: execution reverted: Mailbox: ISM verification failed, data: None)"
+        ));
+        // `Error(string)` selector followed by the ABI-encoded revert reason
+        let revert_data = format!(
+            "Contract call reverted with data: 0x08c379a0{}{}{}",
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            ethers::utils::hex::encode(ISM_VERIFICATION_FAILED),
+        );
+        assert!(is_ism_revert(&revert_data));
+        assert!(!is_ism_revert(
+            &"execution reverted: Mailbox: already delivered"
+        ));
+    }
+}
@@ -32,6 +32,8 @@ pub struct MessageProcessor {
     message_blacklist: Arc<MatchingList>,
     /// Addresses that messages may not interact with.
     address_blacklist: Arc<AddressBlacklist>,
+    /// Messages with a larger body are not relayed, if set.
+    max_message_body_bytes: Option<usize>,
     metrics: MessageProcessorMetrics,
     /// channel for each destination chain to send operations (i.e. message
     /// submissions) to
@@ -277,6 +279,19 @@ impl ProcessorExt for MessageProcessor {
                 return Ok(());
             }
 
+            // Skip if the message's body is larger than configured
+            if let Some(max_message_body_bytes) = self.max_message_body_bytes {
+                if msg.body.len() > max_message_body_bytes {
+                    debug!(
+                        ?msg,
+                        body_len = msg.body.len(),
+                        max_message_body_bytes,
+                        "Message body too large, skipping"
+                    );
+                    return Ok(());
+                }
+            }
+
             // Skip if the message is intended for this origin
             if destination == self.domain().id() {
                 debug!(?msg, "Message destined for self, skipping");
@@ -316,6 +331,7 @@ impl MessageProcessor {
         message_whitelist: Arc<MatchingList>,
         message_blacklist: Arc<MatchingList>,
         address_blacklist: Arc<AddressBlacklist>,
+        max_message_body_bytes: Option<usize>,
         metrics: MessageProcessorMetrics,
         send_channels: HashMap<u32, UnboundedSender<QueueOperation>>,
         destination_ctxs: HashMap<u32, Arc<MessageContext>>,
@@ -325,6 +341,7 @@ impl MessageProcessor {
             message_whitelist,
             message_blacklist,
             address_blacklist,
+            max_message_body_bytes,
             metrics,
             send_channels,
             destination_ctxs,
@@ -390,7 +407,7 @@ mod test {
         merkle_tree::builder::MerkleTreeBuilder,
         msg::{
             gas_payment::GasPaymentEnforcer,
-            metadata::{BaseMetadataBuilder, IsmAwareAppContextClassifier},
+            metadata::{BaseMetadataBuilder, IsmAwareAppContextClassifier, RecipientIsmCache},
         },
         processor::Processor,
     };
@@ -491,8 +508,10 @@ mod test {
         db: &HyperlaneRocksDB,
     ) -> (MessageProcessor, UnboundedReceiver<QueueOperation>) {
         let base_metadata_builder = dummy_metadata_builder(origin_domain, destination_domain, db);
+        let destination_mailbox = Arc::new(MockMailboxContract::default());
         let message_context = Arc::new(MessageContext {
-            destination_mailbox: Arc::new(MockMailboxContract::default()),
+            destination_mailbox: destination_mailbox.clone(),
+            recipient_ism_cache: Arc::new(RecipientIsmCache::new(destination_mailbox)),
             origin_db: db.clone(),
             metadata_builder: Arc::new(base_metadata_builder),
             origin_gas_payment_enforcer: Arc::new(GasPaymentEnforcer::new([], db.clone())),
@@ -507,6 +526,7 @@ mod test {
                 Default::default(),
                 Default::default(),
                 Default::default(),
+                None,
                 dummy_processor_metrics(origin_domain.id()),
                 HashMap::from([(destination_domain.id(), send_channel)]),
                 HashMap::from([(destination_domain.id(), message_context)]),
@@ -561,9 +581,16 @@ mod test {
         db: &HyperlaneRocksDB,
         num_operations: usize,
     ) -> Vec<QueueOperation> {
-        let (message_processor, mut receive_channel) =
+        let (message_processor, receive_channel) =
             dummy_message_processor(origin_domain, destination_domain, db);
+        get_first_n_operations(message_processor, receive_channel, num_operations).await
+    }
 
+    async fn get_first_n_operations(
+        message_processor: MessageProcessor,
+        mut receive_channel: UnboundedReceiver<QueueOperation>,
+        num_operations: usize,
+    ) -> Vec<QueueOperation> {
         let processor = Processor::new(Box::new(message_processor), TaskMonitor::new());
         let process_fut = processor.spawn();
         let mut pending_messages = vec![];
@@ -795,6 +822,37 @@ mod test {
         .await;
     }
 
+    #[tokio::test]
+    async fn test_processor_skips_messages_above_max_body_size() {
+        test_utils::run_test_db(|db| async move {
+            let origin_domain = dummy_domain(0, "dummy_origin_domain");
+            let destination_domain = dummy_domain(1, "dummy_destination_domain");
+            let db = HyperlaneRocksDB::new(&origin_domain, db);
+
+            let messages: Vec<HyperlaneMessage> = (0..3)
+                .map(|nonce| dummy_hyperlane_message(&destination_domain, nonce))
+                .collect();
+            let oversized = HyperlaneMessage {
+                body: vec![0xab; 11],
+                ..messages[1].clone()
+            };
+            add_db_entry(&db, &messages[0], 0);
+            add_db_entry(&db, &oversized, 0);
+            add_db_entry(&db, &messages[2], 0);
+
+            let (mut message_processor, receive_channel) =
+                dummy_message_processor(&origin_domain, &destination_domain, &db);
+            message_processor.max_message_body_bytes = Some(10);
+            let pending_messages =
+                get_first_n_operations(message_processor, receive_channel, 2).await;
+
+            // The oversized message is skipped, and the next nonce is still processed
+            let ids: Vec<H256> = pending_messages.iter().map(|pm| pm.id()).collect();
+            assert_eq!(ids, vec![messages[0].id(), messages[2].id()]);
+        })
+        .await;
+    }
+
     #[tokio::test]
     async fn test_forward_backward_iterator() {
         let mut mock_db = MockDb::new();
@@ -18,8 +18,8 @@ use hyperlane_base::{
 };
 use hyperlane_core::{
     rpc_clients::call_and_retry_n_times, ChainCommunicationError, ContractSyncCursor,
-    HyperlaneDomain, HyperlaneMessage, InterchainGasPayment, MerkleTreeInsertion, QueueOperation,
-    H512, U256,
+    HyperlaneDomain, HyperlaneMessage, InterchainGasPayment, Mailbox, MerkleTreeInsertion,
+    QueueOperation, H512, U256,
 };
 use tokio::{
     sync::{
@@ -30,14 +30,14 @@ use tokio::{
     task::JoinHandle,
 };
 use tokio_metrics::TaskMonitor;
-use tracing::{error, info, info_span, instrument::Instrumented, warn, Instrument};
+use tracing::{error, info, info_span, instrument::Instrumented, Instrument};
 
 use crate::{
     merkle_tree::builder::MerkleTreeBuilder,
     msg::{
         blacklist::AddressBlacklist,
         gas_payment::GasPaymentEnforcer,
-        metadata::{BaseMetadataBuilder, IsmAwareAppContextClassifier},
+        metadata::{BaseMetadataBuilder, IsmAwareAppContextClassifier, RecipientIsmCache},
         op_submitter::{SerialSubmitter, SerialSubmitterMetrics},
         pending_message::{MessageContext, MessageSubmissionMetrics},
         processor::{MessageProcessor, MessageProcessorMetrics},
@@ -76,9 +76,12 @@ pub struct Relayer {
     prover_syncs: HashMap<HyperlaneDomain, Arc<RwLock<MerkleTreeBuilder>>>,
     merkle_tree_hook_syncs: HashMap<HyperlaneDomain, Arc<dyn ContractSyncer<MerkleTreeInsertion>>>,
     dbs: HashMap<HyperlaneDomain, HyperlaneRocksDB>,
+    /// Mailbox of each origin and destination chain
+    mailboxes: HashMap<HyperlaneDomain, Arc<dyn Mailbox>>,
     message_whitelist: Arc<MatchingList>,
     message_blacklist: Arc<MatchingList>,
     address_blacklist: Arc<AddressBlacklist>,
+    max_message_body_bytes: Option<usize>,
     transaction_gas_limit: Option<U256>,
     skip_transaction_gas_limit_for: HashSet<u32>,
     allow_local_checkpoint_syncers: bool,
@@ -136,7 +139,10 @@ impl BaseAgent for Relayer {
             .collect::<HashMap<_, _>>();
 
         let mailboxes = settings
-            .build_mailboxes(settings.destination_chains.iter(), &core_metrics)
+            .build_mailboxes(
+                settings.origin_chains.union(&settings.destination_chains),
+                &core_metrics,
+            )
             .await?;
         let validator_announces = settings
             .build_validator_announces(settings.origin_chains.iter(), &core_metrics)
@@ -243,6 +249,10 @@ impl BaseAgent for Relayer {
                     transaction_gas_limit
                 };
 
+            // shared by all origins, since recipient ISMs only depend on the destination
+            let recipient_ism_cache =
+                Arc::new(RecipientIsmCache::new(mailboxes[destination].clone()));
+
             for origin in &settings.origin_chains {
                 let db = dbs.get(origin).unwrap().clone();
                 let metadata_builder = BaseMetadataBuilder::new(
@@ -266,6 +276,7 @@ impl BaseAgent for Relayer {
                     },
                     Arc::new(MessageContext {
                         destination_mailbox: mailboxes[destination].clone(),
+                        recipient_ism_cache: recipient_ism_cache.clone(),
                         origin_db: dbs.get(origin).unwrap().clone(),
                         metadata_builder: Arc::new(metadata_builder),
                         origin_gas_payment_enforcer: gas_payment_enforcers[origin].clone(),
@@ -278,6 +289,7 @@ impl BaseAgent for Relayer {
 
         Ok(Self {
             dbs,
+            mailboxes,
             origin_chains: settings.origin_chains,
             destination_chains,
             msg_ctxs,
@@ -289,6 +301,7 @@ impl BaseAgent for Relayer {
             message_whitelist,
             message_blacklist,
             address_blacklist,
+            max_message_body_bytes: settings.max_message_body_bytes,
             transaction_gas_limit,
             skip_transaction_gas_limit_for,
             allow_local_checkpoint_syncers: settings.allow_local_checkpoint_syncers,
@@ -450,7 +463,12 @@ impl Relayer {
         origin: &HyperlaneDomain,
         task_monitor: TaskMonitor,
     ) -> Instrumented<JoinHandle<()>> {
-        let index_settings = self.as_ref().settings.chains[origin.name()].index_settings();
+        let mut index_settings = self.as_ref().settings.chains[origin.name()].index_settings();
+        // Nothing can have been dispatched before the mailbox was deployed
+        let start_block = self.mailboxes[origin]
+            .backfill_start_block(index_settings.from)
+            .await;
+        index_settings.from = index_settings.from.max(start_block);
         let contract_sync = self.message_syncs.get(origin).unwrap().clone();
         let cursor_instantiation_result =
             Self::instantiate_cursor_with_retries(contract_sync.clone(), index_settings.clone())
@@ -567,6 +585,7 @@ impl Relayer {
             self.message_whitelist.clone(),
             self.message_blacklist.clone(),
             self.address_blacklist.clone(),
+            self.max_message_body_bytes,
             metrics,
             send_channels,
             destination_ctxs,
@@ -51,6 +51,9 @@ pub struct RelayerSettings {
     /// This is intentionally not an H256 to allow for addresses of any length without
     /// adding any padding.
     pub address_blacklist: Vec<Vec<u8>>,
+    /// This is optional. If specified, messages with a larger body in bytes
+    /// are not relayed.
+    pub max_message_body_bytes: Option<usize>,
     /// This is optional. If not specified, any amount of gas will be valid, otherwise this
     /// is the max allowed gas in wei to relay a transaction.
     pub transaction_gas_limit: Option<U256>,
@@ -204,6 +207,13 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
             .map(|str| parse_address_list(str, &mut err, || &p.cwp + "address_blacklist"))
             .unwrap_or_default();
 
+        let max_message_body_bytes = p
+            .chain(&mut err)
+            .get_opt_key("maxMessageBodyBytes")
+            .parse_u64()
+            .end()
+            .map(|v| v as usize);
+
         let transaction_gas_limit = p
             .chain(&mut err)
             .get_opt_key("transactionGasLimit")
@@ -282,6 +292,7 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
             whitelist,
             blacklist,
             address_blacklist,
+            max_message_body_bytes,
             transaction_gas_limit,
             skip_transaction_gas_limit_for,
             allow_local_checkpoint_syncers,
@@ -18,7 +18,7 @@ use itertools::Itertools;
 use tracing::instrument;
 
 use hyperlane_core::{
-    utils::bytes_to_hex, BatchItem, ChainCommunicationError, ChainResult, ContractLocator,
+    utils::bytes_to_hex, BatchItem, ChainCommunicationError, ChainResult, ContractLocator, Decode,
     HyperlaneAbi, HyperlaneChain, HyperlaneContract, HyperlaneDomain, HyperlaneMessage,
     HyperlaneProtocolError, HyperlaneProvider, Indexed, Indexer, LogMeta, Mailbox,
     RawHyperlaneMessage, SequenceAwareIndexer, TxCostEstimate, TxOutcome, H160, H256, U256,
@@ -29,7 +29,7 @@ use crate::interfaces::arbitrum_node_interface::ArbitrumNodeInterface;
 use crate::interfaces::i_mailbox::{
     IMailbox as EthereumMailboxInternal, ProcessCall, IMAILBOX_ABI,
 };
-use crate::interfaces::mailbox::DispatchFilter;
+use crate::interfaces::mailbox::{DispatchFilter, Mailbox as EthereumMailboxDeployment};
 use crate::tx::{call_with_reorg_period, fill_tx_gas_params, report_tx};
 use crate::{
     BuildableWithProvider, ConnectionConf, EthereumProvider, EthereumReorgPeriod,
@@ -102,6 +102,7 @@ where
 {
     contract: Arc<EthereumMailboxInternal<M>>,
     provider: Arc<M>,
+    domain: HyperlaneDomain,
     reorg_period: EthereumReorgPeriod,
 }
 
@@ -122,6 +123,7 @@ where
         Self {
             contract,
             provider,
+            domain: locator.domain.clone(),
             reorg_period,
         }
     }
@@ -157,12 +159,10 @@ where
             .await?
             .into_iter()
             .map(|(event, meta)| {
-                (
-                    HyperlaneMessage::from(event.message.to_vec()).into(),
-                    meta.into(),
-                )
+                let message = decode_dispatched_message(&event.message, self.domain.id())?;
+                Ok((message.into(), meta.into()))
             })
-            .collect();
+            .collect::<ChainResult<_>>()?;
 
         events.sort_by(|a, b| a.0.inner().nonce.cmp(&b.0.inner().nonce));
         Ok(events)
@@ -180,19 +180,26 @@ where
             })
         })
         .await;
-        let logs = raw_logs_and_meta
+        raw_logs_and_meta
             .into_iter()
             .map(|(log, log_meta)| {
-                (
-                    HyperlaneMessage::from(log.message.to_vec()).into(),
-                    log_meta,
-                )
+                let message = decode_dispatched_message(&log.message, self.domain.id())?;
+                Ok((message.into(), log_meta))
             })
-            .collect();
-        Ok(logs)
+            .collect()
     }
 }
 
+/// Decode a message dispatched by the mailbox on `origin`. Messages are never
+/// skipped, since a gap in the nonce sequence would stall indexing. One that
+/// can't be decoded or has another origin fails the whole range instead, so
+/// it is retried rather than stored.
+fn decode_dispatched_message(raw: &[u8], origin: u32) -> ChainResult<HyperlaneMessage> {
+    let message = HyperlaneMessage::read_from(&mut &raw[..])?;
+    message.validate_origin(origin)?;
+    Ok(message)
+}
+
 #[async_trait]
 impl<M> SequenceAwareIndexer<HyperlaneMessage> for EthereumMailboxIndexer<M>
 where
@@ -309,6 +316,13 @@ where
         }
     }
 
+    /// Binding to the full Mailbox implementation, for functions such as
+    /// `deployedBlock`, `processedAt` and `VERSION` that aren't part of the
+    /// IMailbox interface.
+    fn deployment_contract(&self) -> EthereumMailboxDeployment<M> {
+        EthereumMailboxDeployment::new(self.contract.address(), self.provider.clone())
+    }
+
     /// Returns a ContractCall that processes the provided message.
     async fn process_contract_call(
         &self,
@@ -347,25 +361,11 @@ where
         let batch = multicall::batch::<_, ()>(multicall, contract_calls.clone()).await?;
         let call_results = batch.call().await?;
 
-        let failed_calls = contract_calls
-            .iter()
-            .zip(call_results.iter())
-            .enumerate()
-            .filter_map(
-                |(index, (_, result))| {
-                    if !result.success {
-                        Some(index)
-                    } else {
-                        None
-                    }
-                },
-            )
-            .collect_vec();
+        let failed_calls = failed_call_indexes(&call_results);
 
         // only send a batch if there are at least two successful calls
         let call_count = contract_calls.len();
-        let successful_calls = call_count - failed_calls.len();
-        if successful_calls >= 2 {
+        if is_batch_submittable(call_count, &failed_calls) {
             Ok(BatchSimulation::new(
                 Some(self.submittable_batch(batch)),
                 failed_calls,
@@ -388,6 +388,20 @@ where
     }
 }
 
+/// Indexes of the calls that reverted when simulating a batch
+fn failed_call_indexes(call_results: &[MulticallResult]) -> Vec<usize> {
+    call_results
+        .iter()
+        .enumerate()
+        .filter_map(|(index, result)| (!result.success).then_some(index))
+        .collect_vec()
+}
+
+/// A batch is only worth submitting if at least two of its calls succeed
+fn is_batch_submittable(call_count: usize, failed_calls: &[usize]) -> bool {
+    call_count.saturating_sub(failed_calls.len()) >= 2
+}
+
 #[derive(new)]
 pub struct BatchSimulation<M> {
     pub call: Option<SubmittableBatch<M>>,
@@ -495,6 +509,48 @@ where
             .into())
     }
 
+    #[instrument(skip(self))]
+    async fn processed_block(&self, id: H256) -> ChainResult<Option<u64>> {
+        // `processedAt` is the block number of the `process` call, or zero if
+        // the message hasn't been processed
+        let block = self
+            .deployment_contract()
+            .processed_at(id.into())
+            .call()
+            .await?;
+        Ok((block != 0).then_some(block))
+    }
+
+    #[instrument(skip(self))]
+    async fn deployed_block(&self) -> ChainResult<u32> {
+        let block = self.deployment_contract().deployed_block().call().await?;
+        u32::try_from(block).map_err(|_| {
+            ChainCommunicationError::from_other_str(&format!(
+                "Mailbox deployment block {block} does not fit in a u32"
+            ))
+        })
+    }
+
+    #[instrument(skip(self))]
+    async fn version(&self) -> ChainResult<u8> {
+        Ok(self.deployment_contract().version().call().await?)
+    }
+
+    #[instrument(skip(self))]
+    async fn default_hook(&self) -> ChainResult<H256> {
+        Ok(self.contract.default_hook().call().await?.into())
+    }
+
+    #[instrument(skip(self))]
+    async fn required_hook(&self) -> ChainResult<H256> {
+        Ok(self.contract.required_hook().call().await?.into())
+    }
+
+    #[instrument(skip(self))]
+    async fn owner(&self) -> ChainResult<H256> {
+        Ok(self.deployment_contract().owner().call().await?.into())
+    }
+
     #[instrument(skip(self), fields(metadata=%bytes_to_hex(metadata)))]
     async fn process(
         &self,
@@ -614,20 +670,30 @@ mod test {
     use std::{str::FromStr, sync::Arc};
 
     use ethers::{
-        providers::{MockProvider, Provider},
-        types::{Block, Transaction, U256 as EthersU256},
+        abi::{encode, Token},
+        providers::{JsonRpcError, MockProvider, MockResponse, Provider},
+        types::{Block, Bytes, Log, Transaction, U256 as EthersU256, U64},
     };
+    use ethers_contract::{EthEvent, MulticallResult};
 
     use hyperlane_core::{
-        ContractLocator, HyperlaneDomain, HyperlaneMessage, KnownHyperlaneDomain, Mailbox,
-        TxCostEstimate, H160, H256, U256,
+        ContractLocator, HyperlaneDomain, HyperlaneMessage, Indexer, KnownHyperlaneDomain, Mailbox,
+        RawHyperlaneMessage, TxCostEstimate, H160, H256, U256,
     };
 
     use crate::{
-        contracts::EthereumMailbox, tx::apply_gas_estimate_buffer, ConnectionConf,
-        RpcConnectionConf,
+        contracts::{EthereumMailbox, EthereumMailboxIndexer},
+        tx::apply_gas_estimate_buffer,
+        ConnectionConf, EthereumReorgPeriod, RpcConnectionConf,
+    };
+
+    use super::{
+        decode_dispatched_message, failed_call_indexes, is_batch_submittable, DispatchFilter,
     };
 
+    /// A message body larger than any relayer would usually deliver
+    const LARGE_BODY_BYTES: usize = 1024 * 1024 + 1;
+
     fn get_test_mailbox(
         domain: HyperlaneDomain,
     ) -> (
@@ -764,4 +830,265 @@ mod test {
             },
         );
     }
+
+    fn multicall_result(success: bool) -> MulticallResult {
+        MulticallResult {
+            success,
+            return_data: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_batch_simulation_reports_failing_index() {
+        let call_results = [true, true, false, true]
+            .into_iter()
+            .map(multicall_result)
+            .collect::<Vec<_>>();
+
+        let failed_calls = failed_call_indexes(&call_results);
+        assert_eq!(failed_calls, vec![2]);
+        assert!(is_batch_submittable(call_results.len(), &failed_calls));
+    }
+
+    #[test]
+    fn test_batch_simulation_requires_two_successful_calls() {
+        let call_results = [false, true, false]
+            .into_iter()
+            .map(multicall_result)
+            .collect::<Vec<_>>();
+
+        let failed_calls = failed_call_indexes(&call_results);
+        assert_eq!(failed_calls, vec![0, 2]);
+        assert!(!is_batch_submittable(call_results.len(), &failed_calls));
+    }
+
+    #[test]
+    fn test_dispatched_messages_are_never_skipped() {
+        let message = HyperlaneMessage {
+            origin: 1,
+            body: vec![0xab; LARGE_BODY_BYTES],
+            ..Default::default()
+        };
+        assert_eq!(
+            decode_dispatched_message(&RawHyperlaneMessage::from(&message), 1).unwrap(),
+            message
+        );
+
+        // Shorter than the message header, so the range fails rather than
+        // leaving a gap
+        assert!(decode_dispatched_message(&[0u8; 10], 1).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_range_with_unexpected_origin_fails() {
+        let (indexer, mock_provider) = get_test_mailbox_indexer();
+        let messages = [
+            HyperlaneMessage {
+                origin: KnownHyperlaneDomain::Ethereum as u32,
+                ..Default::default()
+            },
+            HyperlaneMessage {
+                origin: KnownHyperlaneDomain::Ethereum as u32 + 1,
+                nonce: 1,
+                ..Default::default()
+            },
+        ];
+
+        // RPC 1: eth_getLogs for `Dispatch`
+        mock_provider
+            .push(messages.iter().map(dispatch_log).collect::<Vec<_>>())
+            .unwrap();
+
+        // The whole range fails, so neither message is stored and the cursor
+        // queries it again
+        assert!(
+            Indexer::<HyperlaneMessage>::fetch_logs_in_range(&indexer, 0..=100)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_large_dispatched_message_is_indexed() {
+        let (indexer, mock_provider) = get_test_mailbox_indexer();
+        let messages: Vec<HyperlaneMessage> = [0, LARGE_BODY_BYTES, 0]
+            .into_iter()
+            .enumerate()
+            .map(|(nonce, body_len)| HyperlaneMessage {
+                origin: KnownHyperlaneDomain::Ethereum as u32,
+                nonce: nonce as u32,
+                body: vec![0xab; body_len],
+                ..Default::default()
+            })
+            .collect();
+
+        // RPC 1: eth_getLogs for `Dispatch`
+        mock_provider
+            .push(messages.iter().map(dispatch_log).collect::<Vec<_>>())
+            .unwrap();
+
+        let indexed: Vec<HyperlaneMessage> =
+            Indexer::<HyperlaneMessage>::fetch_logs_in_range(&indexer, 0..=100)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|(message, _)| message.inner().clone())
+                .collect();
+
+        // The nonce sequence has no gap for the cursor to rewind to
+        assert_eq!(indexed, messages);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_starts_at_deployed_block() {
+        let (mailbox, mock_provider) =
+            get_test_mailbox(HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum));
+
+        // RPC 1: eth_call to `deployedBlock`
+        let deployed_block = Bytes::from(encode(&[Token::Uint(EthersU256::from(1234))]));
+        mock_provider.push(deployed_block).unwrap();
+
+        assert_eq!(mailbox.backfill_start_block(0).await, 1234);
+    }
+
+    #[tokio::test]
+    async fn test_deployed_block_errors_when_out_of_range() {
+        let (mailbox, mock_provider) =
+            get_test_mailbox(HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum));
+
+        let deployed_block = Bytes::from(encode(&[Token::Uint(EthersU256::from(u64::MAX))]));
+        mock_provider.push(deployed_block).unwrap();
+
+        assert!(mailbox.deployed_block().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_backfill_falls_back_when_deployed_block_reverts() {
+        let (mailbox, mock_provider) =
+            get_test_mailbox(HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum));
+
+        // RPC 1: eth_call to `deployedBlock`, which older mailboxes don't have
+        mock_provider.push_response(MockResponse::Error(JsonRpcError {
+            code: 3,
+            message: "execution reverted".into(),
+            data: None,
+        }));
+
+        assert_eq!(mailbox.backfill_start_block(100).await, 100);
+    }
+
+    #[tokio::test]
+    async fn test_assert_version() {
+        let (mailbox, mock_provider) =
+            get_test_mailbox(HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum));
+        let version = Bytes::from(encode(&[Token::Uint(EthersU256::from(3))]));
+
+        // RPC 1: eth_call to `VERSION`
+        mock_provider.push(version.clone()).unwrap();
+        assert!(mailbox.assert_version(3).await.is_ok());
+
+        // RPC 1: eth_call to `VERSION`
+        mock_provider.push(version).unwrap();
+        let err = mailbox.assert_version(2).await.unwrap_err();
+        assert_eq!(err.to_string(), "Mailbox version is 3, expected 2");
+    }
+
+    #[tokio::test]
+    async fn test_processed_timestamp_resolves_block() {
+        let (mailbox, mock_provider) =
+            get_test_mailbox(HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum));
+
+        // The MockProvider responses we push are processed in LIFO
+        // order, so we start with the final RPCs and work toward the first
+        // RPCs
+
+        // RPC 2: eth_getBlockByNumber for the processing block
+        let block: Block<Transaction> = Block {
+            number: Some(100.into()),
+            hash: Some(ethers::types::H256::repeat_byte(1)),
+            timestamp: 1_700_000_000.into(),
+            ..Block::<Transaction>::default()
+        };
+        mock_provider.push(block).unwrap();
+
+        // RPC 1: eth_call to `processedAt`
+        let processed_at = Bytes::from(encode(&[Token::Uint(EthersU256::from(100))]));
+        mock_provider.push(processed_at).unwrap();
+
+        assert_eq!(
+            mailbox.processed_timestamp(H256::zero()).await.unwrap(),
+            Some(1_700_000_000)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_processed_timestamp_is_none_for_unprocessed_message() {
+        let (mailbox, mock_provider) =
+            get_test_mailbox(HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum));
+
+        // RPC 1: eth_call to `processedAt`
+        let processed_at = Bytes::from(encode(&[Token::Uint(EthersU256::zero())]));
+        mock_provider.push(processed_at).unwrap();
+
+        assert_eq!(
+            mailbox.processed_timestamp(H256::zero()).await.unwrap(),
+            None
+        );
+    }
+
+    fn get_test_mailbox_indexer() -> (
+        EthereumMailboxIndexer<Provider<Arc<MockProvider>>>,
+        Arc<MockProvider>,
+    ) {
+        let mock_provider = Arc::new(MockProvider::new());
+        let provider = Arc::new(Provider::new(mock_provider.clone()));
+        let indexer = EthereumMailboxIndexer::new(
+            provider,
+            &ContractLocator {
+                domain: &HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum),
+                // Address doesn't matter because we're using a MockProvider
+                address: H256::default(),
+            },
+            EthereumReorgPeriod::Blocks(0),
+        );
+        (indexer, mock_provider)
+    }
+
+    /// A log with the metadata the indexer needs set
+    fn indexed_log() -> Log {
+        Log {
+            block_number: Some(U64::from(100)),
+            block_hash: Some(ethers::types::H256::repeat_byte(1)),
+            transaction_hash: Some(ethers::types::H256::repeat_byte(2)),
+            transaction_index: Some(U64::zero()),
+            log_index: Some(EthersU256::zero()),
+            ..Log::default()
+        }
+    }
+
+    fn dispatch_log(message: &HyperlaneMessage) -> Log {
+        Log {
+            topics: vec![
+                DispatchFilter::signature(),
+                ethers::types::H256::zero(),
+                ethers::types::H256::zero(),
+                ethers::types::H256::zero(),
+            ],
+            data: encode(&[Token::Bytes(RawHyperlaneMessage::from(message))]).into(),
+            ..indexed_log()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_owner() {
+        let (mailbox, mock_provider) =
+            get_test_mailbox(HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum));
+        let owner = H160::repeat_byte(0xbb);
+
+        mock_provider
+            .push(Bytes::from(encode(&[Token::Address(owner.into())])))
+            .unwrap();
+
+        assert_eq!(mailbox.owner().await.unwrap(), H256::from(owner));
+    }
 }
@@ -1,4 +1,4 @@
-pub use {interchain_gas::*, mailbox::*, merkle_tree_hook::*, validator_announce::*};
+pub use {interchain_gas::*, mailbox::*, merkle_tree_hook::*, revert::*, validator_announce::*};
 
 pub(crate) use utils::get_finalized_block_number;
 
@@ -6,5 +6,6 @@ mod interchain_gas;
 mod mailbox;
 mod merkle_tree_hook;
 mod multicall;
+mod revert;
 mod utils;
 mod validator_announce;
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use ethers::abi::{self, Abi, AbiError, ParamType, Token};
+use ethers::prelude::Lazy;
+use itertools::Itertools;
+
+use crate::interfaces::{
+    i_aggregation_ism::IAGGREGATIONISM_ABI, i_ccip_read_ism::ICCIPREADISM_ABI,
+    i_interchain_gas_paymaster::IINTERCHAINGASPAYMASTER_ABI,
+    i_interchain_security_module::IINTERCHAINSECURITYMODULE_ABI, i_mailbox::IMAILBOX_ABI,
+    i_multisig_ism::IMULTISIGISM_ABI, i_routing_ism::IROUTINGISM_ABI,
+    i_validator_announce::IVALIDATORANNOUNCE_ABI, mailbox::MAILBOX_ABI,
+    merkle_tree_hook::MERKLETREEHOOK_ABI,
+};
+
+/// Selector of Solidity's built-in `Error(string)`, used by `require` and
+/// `revert` with a reason string
+const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// Selector of Solidity's built-in `Panic(uint256)`, used by failed asserts,
+/// arithmetic overflow, etc.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Custom errors declared in the vendored ABIs, keyed by selector
+static CUSTOM_ERRORS: Lazy<HashMap<[u8; 4], AbiError>> = Lazy::new(|| {
+    let abis: [&Lazy<Abi>; 10] = [
+        &IAGGREGATIONISM_ABI,
+        &ICCIPREADISM_ABI,
+        &IINTERCHAINGASPAYMASTER_ABI,
+        &IINTERCHAINSECURITYMODULE_ABI,
+        &IMAILBOX_ABI,
+        &IMULTISIGISM_ABI,
+        &IROUTINGISM_ABI,
+        &IVALIDATORANNOUNCE_ABI,
+        &MAILBOX_ABI,
+        &MERKLETREEHOOK_ABI,
+    ];
+    abis.into_iter()
+        .flat_map(|abi| abi.errors())
+        .map(|error| (selector(error), error.clone()))
+        .collect()
+});
+
+fn selector(error: &AbiError) -> [u8; 4] {
+    let mut selector = [0u8; 4];
+    selector.copy_from_slice(&error.signature().as_bytes()[..4]);
+    selector
+}
+
+/// Decode the revert data of a failed call into a human readable reason.
+///
+/// Understands `Error(string)`, `Panic(uint256)` and the custom errors
+/// declared in the vendored contract ABIs. Anything else is returned as hex,
+/// noting that the error is unknown.
+pub fn decode_revert_reason(data: &[u8]) -> String {
+    if data.is_empty() {
+        return "reverted without data".to_owned();
+    }
+    if data.len() < 4 {
+        return format!("unknown revert data 0x{}", hex::encode(data));
+    }
+    let (selector, args) = data.split_at(4);
+
+    let decoded = match selector {
+        s if s == ERROR_STRING_SELECTOR => abi::decode(&[ParamType::String], args)
+            .ok()
+            .and_then(|tokens| tokens.into_iter().next())
+            .and_then(Token::into_string)
+            .map(|reason| format!("reverted with reason: {reason}")),
+        s if s == PANIC_SELECTOR => abi::decode(&[ParamType::Uint(256)], args)
+            .ok()
+            .and_then(|tokens| tokens.into_iter().next())
+            .and_then(Token::into_uint)
+            .map(|code| format!("panicked with code {code:#x}")),
+        s => CUSTOM_ERRORS.get(s).and_then(|error| {
+            let tokens = error.decode(args).ok()?;
+            Some(format!(
+                "reverted with {}({})",
+                error.name,
+                tokens.iter().map(ToString::to_string).join(", ")
+            ))
+        }),
+    };
+
+    decoded.unwrap_or_else(|| {
+        format!(
+            "unknown revert selector 0x{}, data: 0x{}",
+            hex::encode(selector),
+            hex::encode(data)
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use ethers::abi::{encode, Token};
+    use ethers::types::U256;
+
+    use super::*;
+
+    #[test]
+    fn test_decodes_error_string() {
+        let data = [
+            ERROR_STRING_SELECTOR.to_vec(),
+            encode(&[Token::String("Mailbox: already delivered".into())]),
+        ]
+        .concat();
+
+        assert_eq!(
+            decode_revert_reason(&data),
+            "reverted with reason: Mailbox: already delivered"
+        );
+    }
+
+    #[test]
+    fn test_decodes_panic() {
+        let data = [
+            PANIC_SELECTOR.to_vec(),
+            encode(&[Token::Uint(U256::from(0x11))]),
+        ]
+        .concat();
+
+        assert_eq!(decode_revert_reason(&data), "panicked with code 0x11");
+    }
+
+    #[test]
+    fn test_decodes_custom_error() {
+        let offchain_lookup = ICCIPREADISM_ABI
+            .errors()
+            .find(|error| error.name == "OffchainLookup")
+            .unwrap();
+        let data = offchain_lookup
+            .encode(&[
+                Token::Address(Default::default()),
+                Token::Array(vec![Token::String("https://example.com".into())]),
+                Token::Bytes(vec![0x12, 0x34]),
+                Token::FixedBytes(vec![0xab, 0xcd, 0xef, 0x01]),
+                Token::Bytes(vec![]),
+            ])
+            .unwrap();
+
+        assert!(decode_revert_reason(&data).starts_with("reverted with OffchainLookup("));
+    }
+
+    #[test]
+    fn test_reports_unknown_revert_data() {
+        assert_eq!(decode_revert_reason(&[]), "reverted without data");
+        assert_eq!(
+            decode_revert_reason(&[0xde, 0xad, 0xbe, 0xef, 0x01]),
+            "unknown revert selector 0xdeadbeef, data: 0xdeadbeef01"
+        );
+    }
+}
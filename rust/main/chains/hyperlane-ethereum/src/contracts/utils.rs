@@ -5,7 +5,7 @@ use ethers::{
     providers::Middleware,
     types::{H160 as EthersH160, H256 as EthersH256},
 };
-use ethers_contract::{ContractError, EthEvent, LogMeta as EthersLogMeta};
+use ethers_contract::{ContractError, EthEvent};
 use hyperlane_core::{ChainCommunicationError, ChainResult, LogMeta, H512};
 use tracing::instrument;
 
@@ -40,11 +40,10 @@ where
                 topics: log.topics.clone(),
                 data: log.data.to_vec(),
             };
-            let log_meta: EthersLogMeta = (&log).into();
-            let event_filter = T::decode_log(&raw_log).ok();
-            event_filter.map(|log| (log, log_meta.into()))
+            let event_filter = T::decode_log(&raw_log).ok()?;
+            Some(LogMeta::from_log(&log).map(|log_meta| (event_filter, log_meta)))
         })
-        .collect();
+        .collect::<ChainResult<_>>()?;
     Ok(logs)
 }
 
@@ -120,7 +120,8 @@ impl From<std::convert::Infallible> for SignersError {
 #[cfg(test)]
 mod test {
     use hyperlane_core::{
-        Checkpoint, CheckpointWithMessageId, HyperlaneSigner, HyperlaneSignerExt, H256,
+        Announcement, Checkpoint, CheckpointWithMessageId, HyperlaneSigner, HyperlaneSignerExt,
+        H256,
     };
 
     use super::Signers;
@@ -153,4 +154,31 @@ mod test {
             .unwrap()
             .block_on(t)
     }
+
+    #[test]
+    fn it_signs_announcement() {
+        let t = async {
+            let signer: Signers =
+                "1111111111111111111111111111111111111111111111111111111111111111"
+                    .parse::<ethers::signers::LocalWallet>()
+                    .unwrap()
+                    .into();
+            let announcement = Announcement {
+                validator: signer.eth_address(),
+                mailbox_address: H256::repeat_byte(2),
+                mailbox_domain: 5,
+                storage_location: "s3://test-bucket/us-east-1".to_owned(),
+            };
+
+            let signed = signer.sign(announcement).await.expect("!sign");
+            assert!(signed.signature.v == 27 || signed.signature.v == 28);
+            assert_eq!(signed.recover().expect("!recover"), signer.eth_address());
+            signed.verify(signer.eth_address()).expect("!verify");
+        };
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(t)
+    }
 }
@@ -21,7 +21,7 @@ use hyperlane_core::{
 };
 use tracing::{debug, error, info, warn};
 
-use crate::{EthereumReorgPeriod, Middleware, TransactionOverrides};
+use crate::{decode_revert_reason, EthereumReorgPeriod, Middleware, TransactionOverrides};
 
 /// An amount of gas to add to the estimated gas
 pub const GAS_ESTIMATE_BUFFER: u32 = 75_000;
@@ -34,12 +34,17 @@ pub fn apply_gas_estimate_buffer(gas: U256, domain: &HyperlaneDomain) -> ChainRe
     // Arbitrum Nitro chains use 2d fees are are especially prone to costs increasing
     // by the time the transaction lands on chain, requiring a higher gas limit.
     // In this case, we apply a multiplier to the gas estimate.
+    // An estimate too large to multiply saturates rather than wrapping or
+    // being divided back down.
     let gas = if domain.is_arbitrum_nitro() {
-        gas.saturating_mul(GAS_ESTIMATE_MULTIPLIER_NUMERATOR.into())
-            .checked_div(GAS_ESTIMATE_MULTIPLIER_DENOMINATOR.into())
-            .ok_or_else(|| {
-                ChainCommunicationError::from_other_str("Gas estimate buffer divide by zero")
-            })?
+        match gas.checked_mul(GAS_ESTIMATE_MULTIPLIER_NUMERATOR.into()) {
+            Some(gas) => gas
+                .checked_div(GAS_ESTIMATE_MULTIPLIER_DENOMINATOR.into())
+                .ok_or_else(|| {
+                    ChainCommunicationError::from_other_str("Gas estimate buffer divide by zero")
+                })?,
+            None => U256::MAX,
+        }
     } else {
         gas
     };
@@ -120,7 +125,18 @@ where
     // either use the pre-estimated gas limit or estimate it
     let mut estimated_gas_limit: U256 = match tx.tx.gas() {
         Some(&estimate) => estimate.into(),
-        None => tx.estimate_gas().await?.into(),
+        None => match tx.estimate_gas().await {
+            Ok(estimate) => estimate.into(),
+            Err(err) => {
+                if let Some(revert_data) = err.as_revert() {
+                    warn!(
+                        reason = %decode_revert_reason(revert_data),
+                        "Gas estimation reverted"
+                    );
+                }
+                return Err(err.into());
+            }
+        },
     };
 
     estimated_gas_limit = apply_gas_estimate_buffer(estimated_gas_limit, domain)?;
@@ -256,3 +272,44 @@ where
         Ok(call)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use hyperlane_core::KnownHyperlaneDomain;
+
+    use super::*;
+
+    #[test]
+    fn test_apply_gas_estimate_buffer() {
+        let estimate = U256::from(1_000_000);
+
+        // Most chains only get the flat buffer
+        let ethereum = HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum);
+        assert_eq!(
+            apply_gas_estimate_buffer(estimate, &ethereum).unwrap(),
+            U256::from(1_075_000)
+        );
+
+        // Arbitrum Nitro chains get the 10% multiplier before the flat buffer
+        let arbitrum = HyperlaneDomain::Known(KnownHyperlaneDomain::Arbitrum);
+        assert_eq!(
+            apply_gas_estimate_buffer(estimate, &arbitrum).unwrap(),
+            U256::from(1_175_000)
+        );
+    }
+
+    #[test]
+    fn test_apply_gas_estimate_buffer_saturates() {
+        let arbitrum = HyperlaneDomain::Known(KnownHyperlaneDomain::Arbitrum);
+        assert_eq!(
+            apply_gas_estimate_buffer(U256::MAX, &arbitrum).unwrap(),
+            U256::MAX
+        );
+
+        let ethereum = HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum);
+        assert_eq!(
+            apply_gas_estimate_buffer(U256::MAX, &ethereum).unwrap(),
+            U256::MAX
+        );
+    }
+}
@@ -0,0 +1,148 @@
+/// Number of consecutive ranges without any logs before the chunk size grows.
+const SPARSE_RANGES_BEFORE_GROWING: u32 = 3;
+
+/// Lowercase fragments of the errors providers return when a `getLogs` range
+/// spans too many blocks or would return too much data.
+const RANGE_TOO_LARGE_ERRORS: [&str; 7] = [
+    "block range",
+    "range too large",
+    "range is too large",
+    "query returned more than",
+    "response size",
+    // e.g. "eth_getLogs is limited to a 10,000 range"
+    "is limited to a",
+    // e.g. "ranges over 10000 blocks are not supported"
+    "ranges over",
+];
+
+/// Whether fetching logs failed because the provider rejected the size of the
+/// queried range, as opposed to e.g. a transient connection error.
+pub(crate) fn is_range_too_large_error(err: &impl ToString) -> bool {
+    let err = err.to_string().to_lowercase();
+    RANGE_TOO_LARGE_ERRORS
+        .iter()
+        .any(|fragment| err.contains(fragment))
+}
+
+/// A block range size that adapts to how busy a chain is.
+///
+/// The chunk size halves whenever the provider rejects a range as too large,
+/// and doubles again after several consecutive ranges without any logs. It
+/// always stays within `min..=max`.
+#[derive(Debug, Clone)]
+pub(crate) struct AdaptiveChunkSize {
+    current: u32,
+    min: u32,
+    max: u32,
+    /// Number of consecutive ranges that returned no logs
+    sparse_ranges: u32,
+}
+
+impl AdaptiveChunkSize {
+    pub fn new(initial: u32, min: u32, max: u32) -> Self {
+        // Never query an empty range
+        let max = max.max(1);
+        let min = min.clamp(1, max);
+        Self {
+            current: initial.clamp(min, max),
+            min,
+            max,
+            sparse_ranges: 0,
+        }
+    }
+
+    /// The current chunk size
+    pub fn get(&self) -> u32 {
+        self.current
+    }
+
+    /// Record the number of logs found in the most recently queried range.
+    pub fn on_logs_found(&mut self, log_count: usize) {
+        if log_count > 0 {
+            self.sparse_ranges = 0;
+            return;
+        }
+        self.sparse_ranges += 1;
+        if self.sparse_ranges >= SPARSE_RANGES_BEFORE_GROWING {
+            self.current = self.current.saturating_mul(2).min(self.max);
+            self.sparse_ranges = 0;
+        }
+    }
+
+    /// Record that the provider rejected the most recently queried range as
+    /// too large.
+    pub fn on_range_too_large(&mut self) {
+        self.current = (self.current / 2).max(self.min);
+        self.sparse_ranges = 0;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_grows_after_sparse_ranges() {
+        let mut chunk_size = AdaptiveChunkSize::new(100, 10, 1000);
+
+        for _ in 0..SPARSE_RANGES_BEFORE_GROWING - 1 {
+            chunk_size.on_logs_found(0);
+        }
+        assert_eq!(chunk_size.get(), 100);
+
+        chunk_size.on_logs_found(0);
+        assert_eq!(chunk_size.get(), 200);
+
+        // finding logs resets the streak of sparse ranges
+        chunk_size.on_logs_found(0);
+        chunk_size.on_logs_found(5);
+        chunk_size.on_logs_found(0);
+        chunk_size.on_logs_found(0);
+        assert_eq!(chunk_size.get(), 200);
+    }
+
+    #[test]
+    fn test_shrinks_when_range_too_large() {
+        let mut chunk_size = AdaptiveChunkSize::new(100, 10, 1000);
+
+        chunk_size.on_range_too_large();
+        assert_eq!(chunk_size.get(), 50);
+    }
+
+    #[test]
+    fn test_stays_within_bounds() {
+        let mut chunk_size = AdaptiveChunkSize::new(100, 30, 150);
+
+        for _ in 0..SPARSE_RANGES_BEFORE_GROWING {
+            chunk_size.on_logs_found(0);
+        }
+        assert_eq!(chunk_size.get(), 150);
+
+        for _ in 0..5 {
+            chunk_size.on_range_too_large();
+        }
+        assert_eq!(chunk_size.get(), 30);
+    }
+
+    #[test]
+    fn test_detects_range_too_large_errors() {
+        for err in [
+            "query returned more than 10000 results",
+            "eth_getLogs block range is too wide",
+            "Log response size exceeded. You can make eth_getLogs requests with up to a 2K block range",
+            "Block range too large, maximum 1000",
+            "eth_getLogs is limited to a 10,000 range",
+            "ranges over 10000 blocks are not supported",
+        ] {
+            assert!(is_range_too_large_error(&err), "{err}");
+        }
+
+        for err in [
+            "error sending request for url (https://rpc.example.com)",
+            "429 Too Many Requests",
+            "connection reset by peer",
+        ] {
+            assert!(!is_range_too_large_error(&err), "{err}");
+        }
+    }
+}
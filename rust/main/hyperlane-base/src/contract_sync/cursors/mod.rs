@@ -5,6 +5,8 @@ use hyperlane_core::{
 };
 pub(crate) use sequence_aware::ForwardBackwardSequenceAwareSyncCursor;
 
+mod adaptive_chunk_size;
+pub(crate) use adaptive_chunk_size::{is_range_too_large_error, AdaptiveChunkSize};
 pub(crate) mod rate_limited;
 pub(crate) use rate_limited::RateLimitedContractSyncCursor;
 
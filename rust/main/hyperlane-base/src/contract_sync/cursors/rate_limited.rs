@@ -12,6 +12,7 @@ use hyperlane_core::{
     ContractSyncCursor, CursorAction, HyperlaneWatermarkedLogStore, Indexed, Indexer, LogMeta,
 };
 
+use super::AdaptiveChunkSize;
 use crate::contract_sync::eta_calculator::SyncerEtaCalculator;
 
 /// Time window for the moving average used in the eta calculator in seconds.
@@ -19,7 +20,7 @@ const ETA_TIME_WINDOW: f64 = 2. * 60.;
 
 #[derive(Debug, new)]
 pub(crate) struct SyncState {
-    chunk_size: u32,
+    chunk_size: AdaptiveChunkSize,
     /// The starting block for the cursor
     start_block: u32,
     /// The next block that should be indexed.
@@ -41,13 +42,13 @@ impl SyncState {
         let (from, to) = match self.direction {
             SyncDirection::Forward => {
                 let from = self.next_block;
-                let mut to = from + self.chunk_size;
+                let mut to = from + self.chunk_size.get();
                 to = u32::min(to, tip);
                 (from, to)
             }
             SyncDirection::Backward => {
                 let to = self.next_block;
-                let from = to.saturating_sub(self.chunk_size);
+                let from = to.saturating_sub(self.chunk_size.get());
                 (from, to)
             }
         };
@@ -90,7 +91,7 @@ impl<T> RateLimitedContractSyncCursor<T> {
     pub async fn new(
         indexer: Arc<dyn Indexer<T>>,
         store: Arc<dyn HyperlaneWatermarkedLogStore<T>>,
-        chunk_size: u32,
+        chunk_size: AdaptiveChunkSize,
         initial_height: u32,
     ) -> Result<Self> {
         let tip = indexer.get_finalized_block_number().await?;
@@ -113,7 +114,7 @@ impl<T> RateLimitedContractSyncCursor<T> {
     /// Wait based on how close we are to the tip and update the tip,
     /// i.e. the highest block we may scrape.
     async fn get_rate_limit(&self) -> Result<Option<Duration>> {
-        if self.sync_state.next_block + self.sync_state.chunk_size < self.tip {
+        if self.sync_state.next_block + self.sync_step() < self.tip {
             // If doing the full chunk wouldn't exceed the already known tip we do not need to rate limit.
             return Ok(None);
         }
@@ -137,7 +138,7 @@ impl<T> RateLimitedContractSyncCursor<T> {
     }
 
     fn sync_step(&self) -> u32 {
-        self.sync_state.chunk_size
+        self.sync_state.chunk_size.get()
     }
 
     async fn get_next_range(&self) -> Result<Option<RangeInclusive<u32>>> {
@@ -182,9 +183,17 @@ where
         self.sync_state.next_block.saturating_sub(1)
     }
 
+    fn chunk_size(&self) -> Option<u32> {
+        Some(self.sync_step())
+    }
+
+    fn on_range_too_large(&mut self, _range: RangeInclusive<u32>) {
+        self.sync_state.chunk_size.on_range_too_large();
+    }
+
     async fn update(
         &mut self,
-        _: Vec<(Indexed<T>, LogMeta)>,
+        logs: Vec<(Indexed<T>, LogMeta)>,
         range: RangeInclusive<u32>,
     ) -> Result<()> {
         // Store a relatively conservative view of the high watermark, which should allow a single watermark to be
@@ -192,12 +201,11 @@ where
         self.store
             .store_high_watermark(u32::max(
                 self.sync_state.start_block,
-                self.sync_state
-                    .next_block
-                    .saturating_sub(self.sync_state.chunk_size),
+                self.sync_state.next_block.saturating_sub(self.sync_step()),
             ))
             .await?;
         self.sync_state.update_range(range);
+        self.sync_state.chunk_size.on_logs_found(logs.len());
 
         match self.indexer.get_finalized_block_number().await {
             Ok(tip) => {
@@ -295,7 +303,7 @@ pub(crate) mod test {
 
         let mut db = MockDb::new();
         db.expect_store_high_watermark().returning(|_| Ok(()));
-        let chunk_size = CHUNK_SIZE;
+        let chunk_size = AdaptiveChunkSize::new(CHUNK_SIZE, 1, CHUNK_SIZE * 8);
         let initial_height = INITIAL_HEIGHT;
         RateLimitedContractSyncCursor::new(
             Arc::new(indexer),
@@ -340,4 +348,37 @@ pub(crate) mod test {
         let (action, _) = cursor.next_action().await.unwrap();
         assert!(matches!(action, CursorAction::Sleep(_)));
     }
+
+    #[tokio::test]
+    async fn test_chunk_size_shrinks_when_range_too_large() {
+        let mut cursor = mock_rate_limited_cursor(None).await;
+        assert_eq!(cursor.chunk_size(), Some(CHUNK_SIZE));
+
+        let (action, _) = cursor.next_action().await.unwrap();
+        let range = match action {
+            CursorAction::Query(range) => range,
+            _ => panic!("Expected Query action"),
+        };
+        cursor.on_range_too_large(range.clone());
+        assert_eq!(cursor.chunk_size(), Some(CHUNK_SIZE / 2));
+
+        // The failed range is retried with the smaller chunk size
+        let (action, _) = cursor.next_action().await.unwrap();
+        let expected_range = *range.start()..=(range.start() + CHUNK_SIZE / 2);
+        assert!(matches!(action, CursorAction::Query(range) if range == expected_range));
+    }
+
+    #[tokio::test]
+    async fn test_chunk_size_grows_above_configured_size_on_sparse_ranges() {
+        let mut cursor = mock_rate_limited_cursor(None).await;
+
+        let mut from = INITIAL_HEIGHT;
+        for _ in 0..3 {
+            let range = from..=(from + CHUNK_SIZE);
+            cursor.update(vec![], range).await.unwrap();
+            from += CHUNK_SIZE + 1;
+        }
+
+        assert_eq!(cursor.chunk_size(), Some(CHUNK_SIZE * 2));
+    }
 }
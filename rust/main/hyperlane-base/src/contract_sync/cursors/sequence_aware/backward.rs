@@ -13,6 +13,7 @@ use tokio::time::sleep;
 use tracing::{debug, instrument, warn};
 
 use super::{LastIndexedSnapshot, TargetSnapshot};
+use crate::contract_sync::cursors::AdaptiveChunkSize;
 
 const MAX_BACKWARD_SYNC_BLOCKING_TIME: Duration = Duration::from_secs(5);
 
@@ -20,8 +21,9 @@ const MAX_BACKWARD_SYNC_BLOCKING_TIME: Duration = Duration::from_secs(5);
 pub(crate) struct BackwardSequenceAwareSyncCursor<T> {
     /// The max chunk size to query for logs.
     /// If in sequence mode, this is the max number of sequences to query.
-    /// If in block mode, this is the max number of blocks to query.
-    chunk_size: u32,
+    /// If in block mode, this is the max number of blocks to query, which
+    /// grows over sparse ranges.
+    chunk_size: AdaptiveChunkSize,
     /// A store used to check which logs have already been indexed.
     store: Arc<dyn HyperlaneSequenceAwareIndexerStoreReader<T>>,
     /// A snapshot of the last log to be indexed, or if no indexing has occurred yet,
@@ -53,7 +55,7 @@ impl<T: Debug> BackwardSequenceAwareSyncCursor<T> {
         ret
     )]
     pub fn new(
-        chunk_size: u32,
+        chunk_size: AdaptiveChunkSize,
         store: Arc<dyn HyperlaneSequenceAwareIndexerStoreReader<T>>,
         current_sequence_count: u32,
         start_block: u32,
@@ -108,7 +110,7 @@ impl<T: Debug> BackwardSequenceAwareSyncCursor<T> {
         // Query the block range ending at the current_indexing_snapshot's at_block.
         current_indexing_snapshot
             .at_block
-            .saturating_sub(self.chunk_size)..=current_indexing_snapshot.at_block
+            .saturating_sub(self.chunk_size.get())..=current_indexing_snapshot.at_block
     }
 
     /// Gets the next sequence range to index.
@@ -120,7 +122,7 @@ impl<T: Debug> BackwardSequenceAwareSyncCursor<T> {
         // Query the sequence range ending at the current_indexing_snapshot's sequence.
         current_indexing_snapshot
             .sequence
-            .saturating_sub(self.chunk_size)..=current_indexing_snapshot.sequence
+            .saturating_sub(self.chunk_size.get())..=current_indexing_snapshot.sequence
     }
 
     /// Reads the DB to check if the current indexing sequence has already been indexed,
@@ -208,6 +210,7 @@ impl<T: Debug> BackwardSequenceAwareSyncCursor<T> {
         }
 
         let logs_len: u32 = logs.len().try_into()?;
+        self.chunk_size.on_logs_found(logs.len());
 
         // If the number of logs, which start at the current sequence and go backwards,
         // exceeds the current indexing snapshot sequence, we've synced everything including
@@ -349,6 +352,14 @@ impl<T: Send + Sync + Clone + Debug + 'static> ContractSyncCursor<T>
             .unwrap_or(self.last_indexed_snapshot.at_block)
     }
 
+    fn chunk_size(&self) -> Option<u32> {
+        Some(self.chunk_size.get())
+    }
+
+    fn on_range_too_large(&mut self, _range: RangeInclusive<u32>) {
+        self.chunk_size.on_range_too_large();
+    }
+
     /// Updates the cursor with the logs that were found in the range.
     ///
     /// Inconsistencies in the logs are not considered errors, instead they're handled by rewinding the cursor
@@ -436,7 +447,7 @@ mod test {
         });
 
         let mut cursor = BackwardSequenceAwareSyncCursor::new(
-            chunk_size,
+            AdaptiveChunkSize::new(chunk_size, chunk_size, chunk_size),
             db,
             INITIAL_SEQUENCE_COUNT,
             INITIAL_START_BLOCK,
@@ -768,7 +779,7 @@ mod test {
             });
 
             let mut cursor = BackwardSequenceAwareSyncCursor::new(
-                CHUNK_SIZE,
+                AdaptiveChunkSize::new(CHUNK_SIZE, CHUNK_SIZE, CHUNK_SIZE),
                 db,
                 INITIAL_SEQUENCE_COUNT,
                 INITIAL_START_BLOCK,
@@ -778,6 +789,28 @@ mod test {
             // We're fully synced, so expect no range
             assert_eq!(cursor.get_next_range().await.unwrap(), None);
         }
+
+        #[tracing_test::traced_test]
+        #[tokio::test]
+        async fn test_chunk_size_adapts_while_backfilling() {
+            let mut cursor = get_cursor().await;
+            cursor.chunk_size = AdaptiveChunkSize::new(CHUNK_SIZE, 1, CHUNK_SIZE * 8);
+
+            // The provider rejects the range, so a smaller one is queried
+            let range = cursor.get_next_range().await.unwrap().unwrap();
+            assert_eq!(range, 900..=1000);
+            cursor.on_range_too_large(range);
+            assert_eq!(cursor.chunk_size(), Some(CHUNK_SIZE / 2));
+            let range = cursor.get_next_range().await.unwrap().unwrap();
+            assert_eq!(range, 950..=1000);
+
+            // Several ranges without any logs grow it again
+            for _ in 0..3 {
+                let range = cursor.get_next_range().await.unwrap().unwrap();
+                cursor.update(vec![], range).await.unwrap();
+            }
+            assert_eq!(cursor.chunk_size(), Some(CHUNK_SIZE));
+        }
     }
 
     mod sequence_range {
@@ -985,7 +1018,7 @@ mod test {
             let mut cursor = get_cursor().await;
 
             // Set the chunk size to 100 to make it easier to test.
-            cursor.chunk_size = 100;
+            cursor.chunk_size = AdaptiveChunkSize::new(100, 100, 100);
 
             // Expect the range to be:
             // (current - chunk_size, current)
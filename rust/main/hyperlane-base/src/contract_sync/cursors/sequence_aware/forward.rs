@@ -37,6 +37,8 @@ pub(crate) struct ForwardSequenceAwareSyncCursor<T> {
     current_indexing_snapshot: TargetSnapshot,
     /// The target snapshot to index towards.
     target_snapshot: Option<TargetSnapshot>,
+    /// The most recently observed onchain sequence count.
+    onchain_sequence_count: Option<u32>,
     /// The mode of indexing.
     index_mode: IndexMode,
 }
@@ -48,6 +50,7 @@ impl<T> Debug for ForwardSequenceAwareSyncCursor<T> {
             .field("last_indexed_snapshot", &self.last_indexed_snapshot)
             .field("current_indexing_snapshot", &self.current_indexing_snapshot)
             .field("target_snapshot", &self.target_snapshot)
+            .field("onchain_sequence_count", &self.onchain_sequence_count)
             .field("index_mode", &self.index_mode)
             .finish()
     }
@@ -84,6 +87,7 @@ impl<T: Debug> ForwardSequenceAwareSyncCursor<T> {
                 at_block: start_block,
             },
             target_snapshot: None,
+            onchain_sequence_count: None,
             index_mode,
         }
     }
@@ -104,6 +108,7 @@ impl<T: Debug> ForwardSequenceAwareSyncCursor<T> {
         else {
             return Ok(None);
         };
+        self.onchain_sequence_count = Some(onchain_sequence_count);
 
         let current_sequence = self.current_indexing_snapshot.sequence;
         let range = match current_sequence.cmp(&onchain_sequence_count) {
@@ -403,6 +408,13 @@ impl<T: Debug> ForwardSequenceAwareSyncCursor<T> {
     }
 }
 
+/// The number of onchain sequences that are yet to be indexed, given the next
+/// sequence to index. Clamped to 0 because providers may briefly report a
+/// sequence count behind what has already been indexed.
+fn indexing_backlog(onchain_sequence_count: u32, next_sequence: u32) -> u32 {
+    onchain_sequence_count.saturating_sub(next_sequence)
+}
+
 #[async_trait]
 impl<T: Send + Sync + Clone + Debug + 'static> ContractSyncCursor<T>
     for ForwardSequenceAwareSyncCursor<T>
@@ -423,6 +435,15 @@ impl<T: Send + Sync + Clone + Debug + 'static> ContractSyncCursor<T>
         self.current_indexing_snapshot.at_block
     }
 
+    fn backlog(&self) -> Option<u32> {
+        self.onchain_sequence_count.map(|onchain_sequence_count| {
+            indexing_backlog(
+                onchain_sequence_count,
+                self.current_indexing_snapshot.sequence,
+            )
+        })
+    }
+
     /// Updates the cursor with the logs that were found in the range.
     ///
     /// Inconsistencies in the logs are not considered errors, instead they're handled by rewinding the cursor
@@ -1225,4 +1246,33 @@ pub(crate) mod test {
             .await;
         }
     }
+
+    #[tokio::test]
+    async fn test_backlog() {
+        let mut cursor =
+            get_test_forward_sequence_aware_sync_cursor(IndexMode::Sequence, 100).await;
+        // No onchain sequence count has been observed yet
+        assert_eq!(cursor.backlog(), None);
+
+        cursor.latest_sequence_querier = Arc::new(MockLatestSequenceQuerier {
+            latest_sequence_count: Some(8),
+            tip: 110,
+        });
+        cursor.get_next_range().await.unwrap();
+        assert_eq!(cursor.backlog(), Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_backlog_clamps_when_indexer_is_ahead() {
+        let mut cursor =
+            get_test_forward_sequence_aware_sync_cursor(IndexMode::Sequence, 100).await;
+
+        // The provider reports a sequence count behind what's already been indexed
+        cursor.latest_sequence_querier = Arc::new(MockLatestSequenceQuerier {
+            latest_sequence_count: Some(2),
+            tip: 100,
+        });
+        assert_eq!(cursor.get_next_range().await.unwrap(), None);
+        assert_eq!(cursor.backlog(), Some(0));
+    }
 }
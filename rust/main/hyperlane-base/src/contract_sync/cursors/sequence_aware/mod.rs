@@ -8,6 +8,8 @@ use hyperlane_core::{
 };
 use std::ops::RangeInclusive;
 
+use super::AdaptiveChunkSize;
+
 mod backward;
 mod forward;
 
@@ -74,7 +76,7 @@ impl<T: Debug> ForwardBackwardSequenceAwareSyncCursor<T> {
     pub async fn new(
         latest_sequence_querier: Arc<dyn SequenceAwareIndexer<T>>,
         store: Arc<dyn HyperlaneSequenceAwareIndexerStoreReader<T>>,
-        chunk_size: u32,
+        chunk_size: AdaptiveChunkSize,
         mode: IndexMode,
     ) -> Result<Self> {
         let (sequence_count, tip) = latest_sequence_querier
@@ -83,8 +85,10 @@ impl<T: Debug> ForwardBackwardSequenceAwareSyncCursor<T> {
         let sequence_count = sequence_count.ok_or(ChainCommunicationError::from_other_str(
             "Failed to query sequence",
         ))?;
+        // Only backfilling adapts the chunk size, the forward cursor mostly
+        // queries the few blocks since the tip
         let forward_cursor = ForwardSequenceAwareSyncCursor::new(
-            chunk_size,
+            chunk_size.get(),
             latest_sequence_querier.clone(),
             store.clone(),
             sequence_count,
@@ -126,6 +130,20 @@ impl<T: Send + Sync + Clone + Debug + 'static> ContractSyncCursor<T>
         self.forward.latest_queried_block()
     }
 
+    fn backlog(&self) -> Option<u32> {
+        self.forward.backlog()
+    }
+
+    fn chunk_size(&self) -> Option<u32> {
+        self.backward.chunk_size()
+    }
+
+    fn on_range_too_large(&mut self, range: RangeInclusive<u32>) {
+        if let SyncDirection::Backward = self.last_direction {
+            self.backward.on_range_too_large(range);
+        }
+    }
+
     async fn update(
         &mut self,
         logs: Vec<(Indexed<T>, LogMeta)>,
@@ -18,6 +18,21 @@ pub struct ContractSyncMetrics {
     /// - `chain`: Chain the indexer is collecting data from.
     pub stored_events: IntCounterVec,
 
+    /// Number of onchain sequences that are yet to be indexed, for sequence
+    /// aware cursors.
+    ///
+    /// Labels:
+    /// - `data_type`: the data the indexer is recording. E.g. `messages` or `gas_payments`.
+    /// - `chain`: Chain the indexer is collecting data from.
+    pub indexer_backlog: IntGaugeVec,
+
+    /// Number of blocks currently queried at once, for cursors that adapt it.
+    ///
+    /// Labels:
+    /// - `data_type`: the data the indexer is recording. E.g. `messages` or `gas_payments`.
+    /// - `chain`: Chain the indexer is collecting data from.
+    pub chunk_size: IntGaugeVec,
+
     /// See `last_known_message_nonce` in CoreMetrics.
     pub message_nonce: IntGaugeVec,
 }
@@ -41,11 +56,29 @@ impl ContractSyncMetrics {
             )
             .expect("failed to register stored_events metric");
 
+        let indexer_backlog = metrics
+            .new_int_gauge(
+                "indexer_backlog",
+                "Number of onchain sequences not yet indexed",
+                &["data_type", "chain"],
+            )
+            .expect("failed to register indexer_backlog metric");
+
+        let chunk_size = metrics
+            .new_int_gauge(
+                "contract_sync_chunk_size",
+                "Number of blocks currently queried at once",
+                &["data_type", "chain"],
+            )
+            .expect("failed to register chunk_size metric");
+
         let message_nonce = metrics.last_known_message_nonce();
 
         ContractSyncMetrics {
             indexed_height,
             stored_events,
+            indexer_backlog,
+            chunk_size,
             message_nonce,
         }
     }
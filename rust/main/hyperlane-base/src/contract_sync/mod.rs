@@ -98,6 +98,14 @@ where
             .metrics
             .stored_events
             .with_label_values(&[label, chain_name]);
+        let backlog_metric = self
+            .metrics
+            .indexer_backlog
+            .with_label_values(&[label, chain_name]);
+        let chunk_size_metric = self
+            .metrics
+            .chunk_size
+            .with_label_values(&[label, chain_name]);
 
         loop {
             if let Some(rx) = opts.tx_id_receiver.as_mut() {
@@ -106,6 +114,12 @@ where
             if let Some(cursor) = opts.cursor.as_mut() {
                 self.fetch_logs_with_cursor(cursor, &stored_logs_metric, &indexed_height_metric)
                     .await;
+                if let Some(backlog) = cursor.backlog() {
+                    backlog_metric.set(backlog as i64);
+                }
+                if let Some(chunk_size) = cursor.chunk_size() {
+                    chunk_size_metric.set(chunk_size as i64);
+                }
             }
         }
     }
@@ -126,7 +140,13 @@ where
                             continue;
                         }
                     };
-                    let logs = self.dedupe_and_store_logs(logs, stored_logs_metric).await;
+                    let logs = match self.dedupe_and_store_logs(logs, stored_logs_metric).await {
+                        Ok(logs) => logs,
+                        Err(err) => {
+                            warn!(?err, ?tx_id, "Error storing logs in db");
+                            continue;
+                        }
+                    };
                     let num_logs = logs.len() as u64;
                     info!(
                         num_logs,
@@ -175,11 +195,22 @@ where
                     Ok(logs) => logs,
                     Err(err) => {
                         warn!(?err, ?range, "Error fetching logs in range");
+                        if is_range_too_large_error(&err) {
+                            cursor.on_range_too_large(range);
+                        }
                         break Some(SLEEP_DURATION);
                     }
                 };
 
-                let logs = self.dedupe_and_store_logs(logs, stored_logs_metric).await;
+                // The cursor must not move past logs that never reached the db,
+                // so the range is queried again instead
+                let logs = match self.dedupe_and_store_logs(logs, stored_logs_metric).await {
+                    Ok(logs) => logs,
+                    Err(err) => {
+                        warn!(?err, ?range, "Error storing logs in db");
+                        break Some(SLEEP_DURATION);
+                    }
+                };
                 let logs_found = logs.len() as u64;
                 info!(
                     ?range,
@@ -221,18 +252,12 @@ where
         &self,
         logs: Vec<(Indexed<T>, LogMeta)>,
         stored_logs_metric: &GenericCounter<AtomicU64>,
-    ) -> Vec<(Indexed<T>, LogMeta)> {
+    ) -> Result<Vec<(Indexed<T>, LogMeta)>> {
         let deduped_logs = HashSet::<_>::from_iter(logs);
         let logs = Vec::from_iter(deduped_logs);
 
         // Store deliveries
-        let stored = match self.store.store_logs(&logs).await {
-            Ok(stored) => stored,
-            Err(err) => {
-                warn!(?err, "Error storing logs in db");
-                Default::default()
-            }
-        };
+        let stored = self.store.store_logs(&logs).await?;
         if stored > 0 {
             debug!(
                 domain = self.domain.as_ref(),
@@ -243,7 +268,7 @@ where
         }
         // Report amount of deliveries stored into db
         stored_logs_metric.inc_by(stored as u64);
-        logs
+        Ok(logs)
     }
 }
 
@@ -306,14 +331,17 @@ where
         let watermark = self.store.retrieve_high_watermark().await.unwrap();
         let index_settings = IndexSettings {
             from: watermark.unwrap_or(index_settings.from),
-            chunk_size: index_settings.chunk_size,
-            mode: index_settings.mode,
+            ..index_settings
         };
         Ok(Box::new(
             RateLimitedContractSyncCursor::new(
                 Arc::new(self.indexer.clone()),
                 self.store.clone(),
-                index_settings.chunk_size,
+                AdaptiveChunkSize::new(
+                    index_settings.chunk_size,
+                    index_settings.min_chunk_size,
+                    index_settings.max_chunk_size,
+                ),
                 index_settings.from,
             )
             .await?,
@@ -354,7 +382,11 @@ where
             ForwardBackwardSequenceAwareSyncCursor::new(
                 self.indexer.clone(),
                 Arc::new(self.store.clone()),
-                index_settings.chunk_size,
+                AdaptiveChunkSize::new(
+                    index_settings.chunk_size,
+                    index_settings.min_chunk_size,
+                    index_settings.max_chunk_size,
+                ),
                 index_settings.mode,
             )
             .await?,
@@ -373,3 +405,94 @@ where
         ContractSync::get_broadcaster(self)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::ops::RangeInclusive;
+
+    use hyperlane_core::{ChainResult, HyperlaneMessage};
+    use prometheus::{IntCounter, IntGauge, Registry};
+
+    use super::*;
+    use crate::CoreMetrics;
+
+    mockall::mock! {
+        pub Indexer {}
+
+        impl Debug for Indexer {
+            fn fmt<'a>(&self, f: &mut std::fmt::Formatter<'a>) -> std::fmt::Result;
+        }
+
+        #[async_trait]
+        impl Indexer<HyperlaneMessage> for Indexer {
+            async fn fetch_logs_in_range(&self, range: RangeInclusive<u32>) -> ChainResult<Vec<(Indexed<HyperlaneMessage>, LogMeta)>>;
+            async fn get_finalized_block_number(&self) -> ChainResult<u32>;
+        }
+    }
+
+    mockall::mock! {
+        pub Db {}
+
+        impl Debug for Db {
+            fn fmt<'a>(&self, f: &mut std::fmt::Formatter<'a>) -> std::fmt::Result;
+        }
+
+        #[async_trait]
+        impl HyperlaneLogStore<HyperlaneMessage> for Db {
+            async fn store_logs(&self, logs: &[(Indexed<HyperlaneMessage>, LogMeta)]) -> Result<u32>;
+        }
+    }
+
+    mockall::mock! {
+        pub Cursor {}
+
+        impl Debug for Cursor {
+            fn fmt<'a>(&self, f: &mut std::fmt::Formatter<'a>) -> std::fmt::Result;
+        }
+
+        #[async_trait]
+        impl ContractSyncCursor<HyperlaneMessage> for Cursor {
+            async fn next_action(&mut self) -> Result<(CursorAction, Duration)>;
+            fn latest_queried_block(&self) -> u32;
+            async fn update(&mut self, logs: Vec<(Indexed<HyperlaneMessage>, LogMeta)>, range: RangeInclusive<u32>) -> Result<()>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cursor_does_not_advance_past_logs_that_failed_to_store() {
+        let mut indexer = MockIndexer::new();
+        indexer.expect_fetch_logs_in_range().returning(|_| {
+            Ok(vec![(
+                HyperlaneMessage::default().into(),
+                LogMeta::default(),
+            )])
+        });
+        let mut db = MockDb::new();
+        db.expect_store_logs()
+            .returning(|_| Err(eyre::eyre!("Message has an unexpected origin")));
+        let mut cursor = MockCursor::new();
+        cursor.expect_latest_queried_block().return_const(0u32);
+        cursor
+            .expect_next_action()
+            .returning(|| Ok((CursorAction::Query(0..=10), Duration::ZERO)));
+        // The range is retried rather than marked as indexed
+        cursor.expect_update().times(0);
+
+        let core_metrics = CoreMetrics::new("test", 8080, Registry::new()).unwrap();
+        let contract_sync = ContractSync::new(
+            HyperlaneDomain::new_test_domain("test"),
+            db,
+            indexer,
+            ContractSyncMetrics::new(&core_metrics),
+        );
+        let stored_logs_metric = IntCounter::new("stored_logs", "stored_logs").unwrap();
+        let indexed_height_metric = IntGauge::new("indexed_height", "indexed_height").unwrap();
+
+        let mut cursor: Box<dyn ContractSyncCursor<HyperlaneMessage>> = Box::new(cursor);
+        contract_sync
+            .fetch_logs_with_cursor(&mut cursor, &stored_logs_metric, &indexed_height_metric)
+            .await;
+
+        assert_eq!(stored_logs_metric.get(), 0);
+    }
+}
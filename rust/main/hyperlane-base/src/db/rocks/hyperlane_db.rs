@@ -286,6 +286,12 @@ impl HyperlaneLogStore<HyperlaneMessage> for HyperlaneRocksDB {
     /// Store a list of dispatched messages and their associated metadata.
     #[instrument(skip_all)]
     async fn store_logs(&self, messages: &[(Indexed<HyperlaneMessage>, LogMeta)]) -> Result<u32> {
+        // A message from another origin means the mailbox is misconfigured for
+        // this domain. Skipping it would leave a gap in the nonce sequence, so
+        // refuse to store the whole batch instead.
+        for (message, _) in messages {
+            message.inner().validate_origin(self.domain().id())?;
+        }
         let mut stored = 0;
         for (message, meta) in messages {
             let stored_message = self.store_message(message.inner(), meta.block_number)?;
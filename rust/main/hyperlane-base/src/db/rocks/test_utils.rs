@@ -31,8 +31,8 @@ where
 #[cfg(test)]
 mod test {
     use hyperlane_core::{
-        HyperlaneDomain, HyperlaneLogStore, HyperlaneMessage, Indexed, LogMeta,
-        RawHyperlaneMessage, H256, H512, U256,
+        GasPaymentKey, HyperlaneDomain, HyperlaneLogStore, HyperlaneMessage, Indexed,
+        InterchainGasPayment, LogMeta, RawHyperlaneMessage, H256, H512, U256,
     };
 
     use crate::db::HyperlaneRocksDB;
@@ -50,7 +50,7 @@ mod test {
             let m = HyperlaneMessage {
                 nonce: 100,
                 version: 3,
-                origin: 10,
+                origin: db.domain().id(),
                 sender: H256::from_low_u64_be(4),
                 destination: 12,
                 recipient: H256::from_low_u64_be(5),
@@ -77,4 +77,83 @@ mod test {
         })
         .await;
     }
+
+    #[tokio::test]
+    async fn db_rejects_messages_with_unexpected_origin() {
+        run_test_db(|db| async move {
+            let db = HyperlaneRocksDB::new(
+                &HyperlaneDomain::new_test_domain("db_rejects_messages_with_unexpected_origin"),
+                db,
+            );
+
+            let valid = HyperlaneMessage {
+                nonce: 99,
+                origin: db.domain().id(),
+                ..Default::default()
+            };
+            let m = HyperlaneMessage {
+                nonce: 100,
+                origin: db.domain().id() + 1,
+                ..Default::default()
+            };
+            let meta = LogMeta {
+                address: H256::from_low_u64_be(1),
+                block_number: 1,
+                block_hash: H256::from_low_u64_be(1),
+                transaction_id: H512::from_low_u64_be(1),
+                transaction_index: 0,
+                log_index: U256::from(0),
+            };
+
+            let err = db
+                .store_logs(&vec![
+                    (Indexed::new(valid.clone()), meta.clone()),
+                    (Indexed::new(m.clone()), meta),
+                ])
+                .await
+                .unwrap_err();
+
+            assert!(err.to_string().contains("has origin"));
+            // Nothing in the batch is stored
+            assert!(db.retrieve_message_by_nonce(valid.nonce).unwrap().is_none());
+            assert!(db.retrieve_message_by_nonce(m.nonce).unwrap().is_none());
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn db_accumulates_gas_payments_for_a_message() {
+        run_test_db(|db| async move {
+            let db = HyperlaneRocksDB::new(
+                &HyperlaneDomain::new_test_domain("db_accumulates_gas_payments_for_a_message"),
+                db,
+            );
+
+            let gas_payment_key = GasPaymentKey {
+                message_id: H256::from_low_u64_be(1),
+                destination: 12,
+            };
+            let payment = |amount: u64| InterchainGasPayment {
+                message_id: gas_payment_key.message_id,
+                destination: gas_payment_key.destination,
+                payment: U256::from(amount),
+                gas_amount: U256::from(amount * 10),
+            };
+
+            let first_meta = LogMeta::random();
+            let second_meta = LogMeta::random();
+            assert!(db.process_gas_payment(payment(1), &first_meta).unwrap());
+            assert!(db.process_gas_payment(payment(2), &second_meta).unwrap());
+            // Re-indexing an already processed payment doesn't count it twice
+            assert!(!db.process_gas_payment(payment(1), &first_meta).unwrap());
+
+            let total = db
+                .retrieve_gas_payment_by_gas_payment_key(gas_payment_key)
+                .unwrap()
+                .unwrap();
+            assert_eq!(total.payment, U256::from(3));
+            assert_eq!(total.gas_amount, U256::from(30));
+        })
+        .await;
+    }
 }
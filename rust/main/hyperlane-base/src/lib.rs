@@ -30,6 +30,10 @@ pub use contract_sync::*;
 mod traits;
 pub use traits::*;
 
+/// Snapshots of a mailbox's security configuration
+mod security_config;
+pub use security_config::*;
+
 mod types;
 pub use types::*;
 
@@ -0,0 +1,307 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use derive_new::new;
+use eyre::Result;
+use hyperlane_core::{HyperlaneDomain, HyperlaneMessage, Mailbox, ModuleType, H256};
+use serde::Serialize;
+use tracing::debug;
+
+use crate::{settings::ChainConf, CoreMetrics};
+
+/// A snapshot of a mailbox's security configuration, e.g. for audits
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityConfig {
+    /// The mailbox's owner
+    pub owner: H256,
+    /// The default ISM, resolved one level into routing and aggregation ISMs
+    pub default_ism: IsmConfig,
+    /// The default post-dispatch hook
+    pub default_hook: H256,
+    /// The required post-dispatch hook
+    pub required_hook: H256,
+}
+
+/// An ISM and, for routing and aggregation ISMs, the ISMs it defers to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsmConfig {
+    /// The ISM's address
+    pub address: H256,
+    /// The ISM's module type
+    pub module_type: ModuleType,
+    /// For a routing ISM, the ISM used for each origin, keyed by origin name
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub routes: BTreeMap<String, IsmConfig>,
+    /// For an aggregation ISM, the ISMs it aggregates
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub modules: Vec<IsmConfig>,
+    /// For an aggregation ISM, how many of `modules` must verify a message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<u8>,
+}
+
+impl IsmConfig {
+    fn new(address: H256, module_type: ModuleType) -> Self {
+        Self {
+            address,
+            module_type,
+            routes: BTreeMap::new(),
+            modules: Vec::new(),
+            threshold: None,
+        }
+    }
+}
+
+/// Reads ISMs by address
+#[async_trait]
+pub trait IsmReader: Send + Sync {
+    /// The module type of the ISM at `ism`
+    async fn module_type(&self, ism: H256) -> Result<ModuleType>;
+
+    /// The ISM the routing ISM at `ism` uses to verify `message`
+    async fn route(&self, ism: H256, message: &HyperlaneMessage) -> Result<H256>;
+
+    /// The ISMs and threshold the aggregation ISM at `ism` uses to verify
+    /// `message`
+    async fn modules_and_threshold(
+        &self,
+        ism: H256,
+        message: &HyperlaneMessage,
+    ) -> Result<(Vec<H256>, u8)>;
+}
+
+/// Reads ISMs deployed on a chain
+#[derive(Debug, new)]
+pub struct ChainIsmReader<'a> {
+    chain: &'a ChainConf,
+    metrics: &'a CoreMetrics,
+}
+
+#[async_trait]
+impl IsmReader for ChainIsmReader<'_> {
+    async fn module_type(&self, ism: H256) -> Result<ModuleType> {
+        let ism = self.chain.build_ism(ism, self.metrics).await?;
+        Ok(ism.module_type().await?)
+    }
+
+    async fn route(&self, ism: H256, message: &HyperlaneMessage) -> Result<H256> {
+        let ism = self.chain.build_routing_ism(ism, self.metrics).await?;
+        Ok(ism.route(message).await?)
+    }
+
+    async fn modules_and_threshold(
+        &self,
+        ism: H256,
+        message: &HyperlaneMessage,
+    ) -> Result<(Vec<H256>, u8)> {
+        let ism = self.chain.build_aggregation_ism(ism, self.metrics).await?;
+        Ok(ism.modules_and_threshold(message).await?)
+    }
+}
+
+/// Export the security configuration of `mailbox`. A routing default ISM is
+/// resolved for messages from each of `origins`; origins it has no route for
+/// are left out.
+pub async fn export_security_config(
+    mailbox: &dyn Mailbox,
+    isms: &dyn IsmReader,
+    origins: &[HyperlaneDomain],
+) -> Result<SecurityConfig> {
+    let default_ism = mailbox.default_ism().await?;
+    Ok(SecurityConfig {
+        owner: mailbox.owner().await?,
+        default_ism: resolve_ism(isms, default_ism, mailbox.domain(), origins).await?,
+        default_hook: mailbox.default_hook().await?,
+        required_hook: mailbox.required_hook().await?,
+    })
+}
+
+async fn resolve_ism(
+    isms: &dyn IsmReader,
+    address: H256,
+    destination: &HyperlaneDomain,
+    origins: &[HyperlaneDomain],
+) -> Result<IsmConfig> {
+    let mut config = IsmConfig::new(address, isms.module_type(address).await?);
+    match config.module_type {
+        ModuleType::Routing => {
+            for origin in origins {
+                let message = HyperlaneMessage {
+                    origin: origin.id(),
+                    destination: destination.id(),
+                    ..Default::default()
+                };
+                let route = match isms.route(address, &message).await {
+                    Ok(route) => route,
+                    Err(err) => {
+                        debug!(?err, %origin, "No route for origin");
+                        continue;
+                    }
+                };
+                let module_type = isms.module_type(route).await?;
+                config
+                    .routes
+                    .insert(origin.name().to_owned(), IsmConfig::new(route, module_type));
+            }
+        }
+        ModuleType::Aggregation => {
+            let message = HyperlaneMessage {
+                destination: destination.id(),
+                ..Default::default()
+            };
+            let (modules, threshold) = isms.modules_and_threshold(address, &message).await?;
+            for module in modules {
+                let module_type = isms.module_type(module).await?;
+                config.modules.push(IsmConfig::new(module, module_type));
+            }
+            config.threshold = Some(threshold);
+        }
+        _ => {}
+    }
+    Ok(config)
+}
+
+#[cfg(test)]
+mod test {
+    use hyperlane_core::KnownHyperlaneDomain;
+    use hyperlane_test::mocks::MockMailboxContract;
+    use serde_json::json;
+
+    use super::*;
+
+    mockall::mock! {
+        pub IsmReader {}
+
+        #[async_trait]
+        impl IsmReader for IsmReader {
+            async fn module_type(&self, ism: H256) -> Result<ModuleType>;
+            async fn route(&self, ism: H256, message: &HyperlaneMessage) -> Result<H256>;
+            async fn modules_and_threshold(&self, ism: H256, message: &HyperlaneMessage) -> Result<(Vec<H256>, u8)>;
+        }
+    }
+
+    fn mock_mailbox(default_ism: H256) -> MockMailboxContract {
+        let mut mailbox = MockMailboxContract::new();
+        mailbox
+            .expect__domain()
+            .return_const(HyperlaneDomain::Known(KnownHyperlaneDomain::Arbitrum));
+        mailbox
+            .expect__owner()
+            .returning(|| Ok(H256::repeat_byte(5)));
+        mailbox
+            .expect__default_ism()
+            .returning(move || Ok(default_ism));
+        mailbox
+            .expect__default_hook()
+            .returning(|| Ok(H256::repeat_byte(6)));
+        mailbox
+            .expect__required_hook()
+            .returning(|| Ok(H256::repeat_byte(7)));
+        mailbox
+    }
+
+    fn hex(h: H256) -> serde_json::Value {
+        serde_json::to_value(h).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_export_security_config_with_routing_default_ism() {
+        let routing = H256::repeat_byte(1);
+        let aggregation = H256::repeat_byte(2);
+        let multisig = H256::repeat_byte(3);
+
+        let mailbox = mock_mailbox(routing);
+
+        let mut isms = MockIsmReader::new();
+        isms.expect_module_type().returning(move |ism| {
+            Ok(match ism {
+                ism if ism == routing => ModuleType::Routing,
+                ism if ism == aggregation => ModuleType::Aggregation,
+                _ => ModuleType::MessageIdMultisig,
+            })
+        });
+        isms.expect_route().returning(move |_, message| {
+            match KnownHyperlaneDomain::try_from(message.origin) {
+                Ok(KnownHyperlaneDomain::Ethereum) => Ok(aggregation),
+                Ok(KnownHyperlaneDomain::Optimism) => Ok(multisig),
+                _ => Err(eyre::eyre!("No ISM found for origin")),
+            }
+        });
+
+        // The aggregation ISM routed to isn't resolved any further, so
+        // `modules_and_threshold` is never called
+        let origins = [
+            HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum),
+            HyperlaneDomain::Known(KnownHyperlaneDomain::Optimism),
+            HyperlaneDomain::Known(KnownHyperlaneDomain::Polygon),
+        ];
+        let config = export_security_config(&mailbox, &isms, &origins)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            json!({
+                "owner": hex(H256::repeat_byte(5)),
+                "defaultIsm": {
+                    "address": hex(routing),
+                    "moduleType": "Routing",
+                    "routes": {
+                        "ethereum": {
+                            "address": hex(aggregation),
+                            "moduleType": "Aggregation",
+                        },
+                        "optimism": {
+                            "address": hex(multisig),
+                            "moduleType": "MessageIdMultisig",
+                        },
+                    },
+                },
+                "defaultHook": hex(H256::repeat_byte(6)),
+                "requiredHook": hex(H256::repeat_byte(7)),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_security_config_with_aggregation_default_ism() {
+        let aggregation = H256::repeat_byte(1);
+        let multisig = H256::repeat_byte(2);
+        let null = H256::repeat_byte(3);
+        let mailbox = mock_mailbox(aggregation);
+
+        let mut isms = MockIsmReader::new();
+        isms.expect_module_type().returning(move |ism| {
+            Ok(match ism {
+                ism if ism == aggregation => ModuleType::Aggregation,
+                ism if ism == null => ModuleType::Null,
+                _ => ModuleType::MerkleRootMultisig,
+            })
+        });
+        isms.expect_modules_and_threshold()
+            .returning(move |_, _| Ok((vec![multisig, null], 1)));
+
+        let config = export_security_config(&mailbox, &isms, &[]).await.unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&config).unwrap()["defaultIsm"],
+            json!({
+                "address": hex(aggregation),
+                "moduleType": "Aggregation",
+                "modules": [
+                    {
+                        "address": hex(multisig),
+                        "moduleType": "MerkleRootMultisig",
+                    },
+                    {
+                        "address": hex(null),
+                        "moduleType": "Null",
+                    },
+                ],
+                "threshold": 1,
+            })
+        );
+    }
+}
@@ -157,6 +157,13 @@ pub struct IndexSettings {
     pub from: u32,
     /// The number of blocks to query at once when indexing contracts.
     pub chunk_size: u32,
+    /// The fewest blocks the rate-limited and backfill cursors query at once
+    /// after a provider rejects a range as too large.
+    pub min_chunk_size: u32,
+    /// The most blocks the rate-limited and backfill cursors query at once
+    /// after growing their range on a quiet chain. Defaults to `chunk_size`,
+    /// so ranges only grow if this is configured.
+    pub max_chunk_size: u32,
     /// The indexing mode.
     pub mode: IndexMode,
 }
@@ -249,4 +249,66 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_parse_s3_storage_location() {
+        use super::*;
+
+        let conf = CheckpointSyncerConf::from_str("s3://bucket/us-east-1").unwrap();
+        assert!(matches!(
+            conf,
+            CheckpointSyncerConf::S3 { ref bucket, folder: None, region: Region::UsEast1 }
+                if bucket == "bucket"
+        ));
+
+        let conf = CheckpointSyncerConf::from_str("s3://bucket/us-east-1/some/folder").unwrap();
+        assert!(matches!(
+            conf,
+            CheckpointSyncerConf::S3 { ref bucket, folder: Some(ref folder), region: Region::UsEast1 }
+                if bucket == "bucket" && folder == "some/folder"
+        ));
+    }
+
+    #[test]
+    fn test_parse_gcs_storage_location() {
+        use super::*;
+
+        let conf = CheckpointSyncerConf::from_str("gs://bucket/").unwrap();
+        assert!(matches!(
+            conf,
+            CheckpointSyncerConf::Gcs { ref bucket, folder: None, .. } if bucket == "bucket"
+        ));
+
+        let conf = CheckpointSyncerConf::from_str("gs://bucket/folder/").unwrap();
+        assert!(matches!(
+            conf,
+            CheckpointSyncerConf::Gcs { ref bucket, folder: Some(ref folder), .. }
+                if bucket == "bucket" && folder == "folder"
+        ));
+    }
+
+    #[test]
+    fn test_parse_local_storage_location() {
+        use super::*;
+
+        let conf = CheckpointSyncerConf::from_str("file:///tmp/checkpoints").unwrap();
+        assert!(matches!(
+            conf,
+            CheckpointSyncerConf::LocalStorage { ref path } if path == &PathBuf::from("/tmp/checkpoints")
+        ));
+    }
+
+    #[test]
+    fn test_parse_malformed_storage_location() {
+        use super::*;
+
+        // no scheme separator
+        assert!(CheckpointSyncerConf::from_str("bucket/us-east-1").is_err());
+        // s3 location without a region
+        assert!(CheckpointSyncerConf::from_str("s3://bucket").is_err());
+        // s3 location with an invalid region
+        assert!(CheckpointSyncerConf::from_str("s3://bucket/not-a-region").is_err());
+        // unknown scheme
+        assert!(CheckpointSyncerConf::from_str("ipfs://bucket/folder").is_err());
+    }
 }
@@ -35,6 +35,7 @@ mod connection_parser;
 mod json_value_parser;
 
 const DEFAULT_CHUNK_SIZE: u32 = 1999;
+const DEFAULT_MIN_CHUNK_SIZE: u32 = 1;
 
 /// The base agent config
 #[derive(Debug, Deserialize)]
@@ -155,6 +156,19 @@ fn parse_chain(
         .get_opt_key("chunk")
         .parse_u32()
         .unwrap_or(DEFAULT_CHUNK_SIZE);
+    let min_chunk_size = chain
+        .chain(&mut err)
+        .get_opt_key("index")
+        .get_opt_key("minChunk")
+        .parse_u32()
+        .unwrap_or(DEFAULT_MIN_CHUNK_SIZE);
+    let max_chunk_size = chain
+        .chain(&mut err)
+        .get_opt_key("index")
+        .get_opt_key("maxChunk")
+        .parse_u32()
+        // The chunk size only grows past `chunk` if a larger max is configured
+        .unwrap_or(chunk_size);
     let mode = chain
         .chain(&mut err)
         .get_opt_key("index")
@@ -233,6 +247,8 @@ fn parse_chain(
         index: IndexSettings {
             from,
             chunk_size,
+            min_chunk_size,
+            max_chunk_size,
             mode,
         },
     })
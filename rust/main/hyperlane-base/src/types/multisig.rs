@@ -22,6 +22,20 @@ pub struct MultisigCheckpointSyncer {
 }
 
 impl MultisigCheckpointSyncer {
+    /// Returns the validators without a usable checkpoint syncer, e.g.
+    /// because they never announced a storage location.
+    pub fn validators_without_syncer(&self, validators: &[H256]) -> Vec<H256> {
+        validators
+            .iter()
+            .filter(|validator| {
+                !self
+                    .checkpoint_syncers
+                    .contains_key(&H160::from(**validator))
+            })
+            .copied()
+            .collect()
+    }
+
     /// Gets the latest checkpoint index from each validator's checkpoint syncer.
     /// Returns a vector of the latest indices, in an unspecified order, and does
     /// not contain indices for validators that did not provide a latest index.
@@ -219,3 +233,171 @@ impl MultisigCheckpointSyncer {
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use ethers::signers::LocalWallet;
+    use hyperlane_core::{
+        Checkpoint, CheckpointWithMessageId, HyperlaneSigner, HyperlaneSignerExt,
+    };
+    use hyperlane_ethereum::Signers;
+    use prometheus::Registry;
+
+    use super::*;
+    use crate::LocalStorage;
+
+    // Well-known anvil development keys
+    const VALIDATOR_KEYS: [&str; 4] = [
+        "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+        "59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+        "5de4111afa1a4b94908f83103eb1f1706367c2e68ca870fc3fb9a804cdab365a",
+        "7c852118294e51e653712a81e05800f419141751be58f605c371e15141b007a6",
+    ];
+
+    const INDEX: u32 = 10;
+
+    struct TestValidator {
+        signer: Signers,
+        syncer: Arc<LocalStorage>,
+    }
+
+    impl TestValidator {
+        fn address(&self) -> H256 {
+            self.signer.eth_address().into()
+        }
+
+        async fn sign(&self, root: H256) -> SignedCheckpointWithMessageId {
+            self.signer.sign(checkpoint(root)).await.unwrap()
+        }
+
+        async fn publish(&self, signed_checkpoint: &SignedCheckpointWithMessageId) {
+            self.syncer
+                .write_checkpoint(signed_checkpoint)
+                .await
+                .unwrap();
+        }
+    }
+
+    fn checkpoint(root: H256) -> CheckpointWithMessageId {
+        CheckpointWithMessageId {
+            checkpoint: Checkpoint {
+                merkle_tree_hook_address: H256::repeat_byte(0xaa),
+                mailbox_domain: 1,
+                root,
+                index: INDEX,
+            },
+            message_id: H256::repeat_byte(0xbb),
+        }
+    }
+
+    fn build_validators(dir: &tempfile::TempDir, count: usize) -> Vec<TestValidator> {
+        VALIDATOR_KEYS[..count]
+            .iter()
+            .enumerate()
+            .map(|(i, key)| TestValidator {
+                signer: LocalWallet::from_str(key).unwrap().into(),
+                syncer: Arc::new(LocalStorage::new(dir.path().join(i.to_string()), None).unwrap()),
+            })
+            .collect()
+    }
+
+    fn build_multisig_syncer(validators: &[TestValidator]) -> MultisigCheckpointSyncer {
+        let checkpoint_syncers = validators
+            .iter()
+            .map(|v| {
+                (
+                    H160::from(v.address()),
+                    v.syncer.clone() as Arc<dyn CheckpointSyncer>,
+                )
+            })
+            .collect();
+        let metrics = Arc::new(CoreMetrics::new("test", 8080, Registry::new()).unwrap());
+        MultisigCheckpointSyncer::new(checkpoint_syncers, metrics, None)
+    }
+
+    #[tokio::test]
+    async fn test_fetch_checkpoint_verifies_signature() {
+        let dir = tempfile::tempdir().unwrap();
+        let validators = build_validators(&dir, 1);
+        let addresses: Vec<H256> = validators.iter().map(TestValidator::address).collect();
+        let syncer = build_multisig_syncer(&validators);
+
+        let signed = validators[0].sign(H256::repeat_byte(1)).await;
+        validators[0].publish(&signed).await;
+        let fetched = syncer
+            .fetch_checkpoint(&addresses, 1, INDEX)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(fetched.checkpoint, signed.value);
+        assert_eq!(fetched.signatures, vec![signed.signature]);
+
+        // Tampering with the root means the signature no longer recovers to the validator
+        let mut tampered = signed;
+        tampered.value.checkpoint.root = H256::repeat_byte(2);
+        validators[0].publish(&tampered).await;
+        assert!(syncer
+            .fetch_checkpoint(&addresses, 1, INDEX)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_checkpoint_quorum_ignores_divergent_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let validators = build_validators(&dir, 4);
+        let addresses: Vec<H256> = validators.iter().map(TestValidator::address).collect();
+        let syncer = build_multisig_syncer(&validators);
+
+        let root = H256::repeat_byte(1);
+        let mut expected_signatures = vec![];
+        for (i, validator) in validators.iter().enumerate() {
+            // The third validator signs a different root
+            let signed = if i == 2 {
+                validator.sign(H256::repeat_byte(2)).await
+            } else {
+                let signed = validator.sign(root).await;
+                expected_signatures.push(signed.signature);
+                signed
+            };
+            validator.publish(&signed).await;
+        }
+
+        let fetched = syncer
+            .fetch_checkpoint(&addresses, 3, INDEX)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(fetched.checkpoint, checkpoint(root));
+        // Signatures are ordered by the validator set ordering
+        assert_eq!(fetched.signatures, expected_signatures);
+
+        // Without the divergent validator there is no quorum of 4
+        assert!(syncer
+            .fetch_checkpoint(&addresses, 4, INDEX)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_validators_without_syncer() {
+        let dir = tempfile::tempdir().unwrap();
+        let validators = build_validators(&dir, 3);
+        let mut addresses: Vec<H256> = validators.iter().map(TestValidator::address).collect();
+        // Only the first two validators have announced a storage location
+        let syncer = build_multisig_syncer(&validators[..2]);
+
+        assert!(syncer.validators_without_syncer(&addresses[..2]).is_empty());
+
+        let unannounced = H256::from(H160::repeat_byte(0xcc));
+        addresses.push(unannounced);
+        assert_eq!(
+            syncer.validators_without_syncer(&addresses),
+            vec![addresses[2], unannounced]
+        );
+    }
+}
@@ -259,4 +259,29 @@ pub enum HyperlaneProtocolError {
     /// Expected a gas limit and none was provided
     #[error("A gas limit was expected for `process` contract call")]
     ProcessGasLimitRequired,
+    /// A signature's recovery ID was not one of 0, 1, 27 or 28
+    #[error("Invalid signature recovery ID ({0})")]
+    InvalidRecoveryId(u64),
+    /// A signature's `s` value is in the upper half of the curve order, so
+    /// another valid signature can be derived from it
+    #[error("Signature is malleable (high s value)")]
+    MalleableSignature,
+    /// A mailbox's version doesn't match the expected version
+    #[error("Mailbox version is {actual}, expected {expected}")]
+    UnexpectedMailboxVersion {
+        /// The expected mailbox version
+        expected: u8,
+        /// The mailbox's actual version
+        actual: u8,
+    },
+    /// A message's origin doesn't match the domain it was dispatched on
+    #[error("Message with nonce {nonce} has origin {origin}, expected {expected}")]
+    UnexpectedOrigin {
+        /// Nonce of the offending message
+        nonce: u32,
+        /// Origin domain ID in the message
+        origin: u32,
+        /// Domain ID of the mailbox that dispatched the message
+        expected: u32,
+    },
 }
@@ -23,6 +23,22 @@ pub trait ContractSyncCursor<T>: Debug + Send + Sync + 'static {
     /// TODO: consider a better way to assess health
     fn latest_queried_block(&self) -> u32;
 
+    /// The number of onchain sequences that have not been indexed yet, if the
+    /// cursor is sequence aware.
+    fn backlog(&self) -> Option<u32> {
+        None
+    }
+
+    /// The number of blocks currently queried at once, if the cursor adapts it
+    /// to the chain's activity.
+    fn chunk_size(&self) -> Option<u32> {
+        None
+    }
+
+    /// Called when the provider rejected a range returned by `next_action`
+    /// because it spans too many blocks or would return too much data.
+    fn on_range_too_large(&mut self, _range: RangeInclusive<u32>) {}
+
     /// Ingests the logs that were fetched from the chain and the range that was queried,
     /// and adjusts the cursor accordingly.
     /// This is called after the logs have been written to the store,
@@ -2,10 +2,12 @@ use std::fmt::Debug;
 
 use async_trait::async_trait;
 use derive_new::new;
+use tracing::debug;
 
 use crate::{
     traits::TxOutcome, utils::domain_hash, BatchItem, ChainCommunicationError, ChainResult,
-    HyperlaneContract, HyperlaneMessage, QueueOperation, ReorgPeriod, TxCostEstimate, H256, U256,
+    HyperlaneContract, HyperlaneMessage, HyperlaneProtocolError, QueueOperation, ReorgPeriod,
+    TxCostEstimate, H256, U256,
 };
 
 /// Interface for the Mailbox chain contract. Allows abstraction over different
@@ -32,6 +34,84 @@ pub trait Mailbox: HyperlaneContract + Send + Sync + Debug {
     /// Get the latest checkpoint.
     async fn recipient_ism(&self, recipient: H256) -> ChainResult<H256>;
 
+    /// Fetch the block number at which a message was processed, or `None` if
+    /// it hasn't been processed
+    async fn processed_block(&self, _id: H256) -> ChainResult<Option<u64>> {
+        Err(ChainCommunicationError::from_other_str(
+            "Mailbox does not record when messages were processed",
+        ))
+    }
+
+    /// Fetch the unix timestamp, in seconds, of the block in which a message
+    /// was processed, or `None` if it hasn't been processed
+    async fn processed_timestamp(&self, id: H256) -> ChainResult<Option<u64>> {
+        let Some(block) = self.processed_block(id).await? else {
+            return Ok(None);
+        };
+        let block = self.provider().get_block_by_height(block).await?;
+        Ok(Some(block.timestamp))
+    }
+
+    /// Fetch the block number at which the mailbox was deployed
+    async fn deployed_block(&self) -> ChainResult<u32> {
+        Err(ChainCommunicationError::from_other_str(
+            "Mailbox does not record its deployment block",
+        ))
+    }
+
+    /// The block to start backfilling dispatched messages from. This is the
+    /// block at which the mailbox was deployed, or `fallback` if that can't
+    /// be read, e.g. for older deployments.
+    async fn backfill_start_block(&self, fallback: u32) -> u32 {
+        match self.deployed_block().await {
+            Ok(block) => block,
+            Err(err) => {
+                debug!(?err, fallback, "Failed to fetch mailbox deployment block");
+                fallback
+            }
+        }
+    }
+
+    /// Fetch the mailbox's `VERSION`, the message version it dispatches and
+    /// processes
+    async fn version(&self) -> ChainResult<u8> {
+        Err(ChainCommunicationError::from_other_str(
+            "Mailbox does not expose its version",
+        ))
+    }
+
+    /// Check that the mailbox's version is `expected`
+    async fn assert_version(&self, expected: u8) -> ChainResult<()> {
+        let actual = self.version().await?;
+        if actual != expected {
+            return Err(
+                HyperlaneProtocolError::UnexpectedMailboxVersion { expected, actual }.into(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Fetch the current default post-dispatch hook
+    async fn default_hook(&self) -> ChainResult<H256> {
+        Err(ChainCommunicationError::from_other_str(
+            "Mailbox does not expose its default hook",
+        ))
+    }
+
+    /// Fetch the current required post-dispatch hook
+    async fn required_hook(&self) -> ChainResult<H256> {
+        Err(ChainCommunicationError::from_other_str(
+            "Mailbox does not expose its required hook",
+        ))
+    }
+
+    /// Fetch the address of the mailbox's owner
+    async fn owner(&self) -> ChainResult<H256> {
+        Err(ChainCommunicationError::from_other_str(
+            "Mailbox does not expose its owner",
+        ))
+    }
+
     /// Process a message with a proof against the provided signed checkpoint
     async fn process(
         &self,
@@ -1,4 +1,6 @@
 use std::fmt::Debug;
+#[cfg(feature = "async")]
+use std::{sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use auto_impl::auto_impl;
@@ -7,6 +9,8 @@ use crate::{
     accumulator::incremental::IncrementalMerkle, ChainResult, Checkpoint, HyperlaneContract,
     ReorgPeriod,
 };
+#[cfg(feature = "async")]
+use crate::{rpc_clients::call_and_retry_indefinitely, H256};
 
 /// Interface for the MerkleTreeHook chain contract. Allows abstraction over different
 /// chains
@@ -31,3 +35,136 @@ pub trait MerkleTreeHook: HyperlaneContract + Send + Sync + Debug {
     ///   it will query at the latest block.
     async fn latest_checkpoint(&self, reorg_period: &ReorgPeriod) -> ChainResult<Checkpoint>;
 }
+
+/// Watch the finalized roots of a merkle tree hook, yielding `(root, index)`
+/// every time the latest checkpoint changes.
+///
+/// - `reorg_period` is how far behind the current block to query, so only
+///   finalized insertions are observed.
+/// - `cursor` is the last `(root, index)` already seen by the caller, if any.
+///   It is not yielded again.
+/// - `interval` is how long to wait between polls.
+///
+/// Consecutive identical roots are deduplicated, and failed queries are
+/// retried indefinitely.
+#[cfg(feature = "async")]
+pub fn watch_roots(
+    merkle_tree_hook: Arc<dyn MerkleTreeHook>,
+    reorg_period: ReorgPeriod,
+    cursor: Option<(H256, u32)>,
+    interval: Duration,
+) -> impl futures::Stream<Item = (H256, u32)> {
+    futures::stream::unfold(cursor, move |last_seen| {
+        let merkle_tree_hook = merkle_tree_hook.clone();
+        let reorg_period = reorg_period.clone();
+        async move {
+            loop {
+                let checkpoint = call_and_retry_indefinitely(|| {
+                    let merkle_tree_hook = merkle_tree_hook.clone();
+                    let reorg_period = reorg_period.clone();
+                    Box::pin(async move { merkle_tree_hook.latest_checkpoint(&reorg_period).await })
+                })
+                .await;
+                let latest = (checkpoint.root, checkpoint.index);
+                if last_seen.map(|(root, _)| root) != Some(checkpoint.root) {
+                    return Some((latest, Some(latest)));
+                }
+                tokio::time::sleep(interval).await;
+            }
+        }
+    })
+}
+
+#[cfg(all(test, feature = "async"))]
+mod test {
+    use std::{collections::VecDeque, sync::Mutex};
+
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::{test_utils::dummy_domain, HyperlaneChain, HyperlaneDomain, HyperlaneProvider};
+
+    /// Returns the scripted checkpoints in order, repeating the last one
+    #[derive(Debug)]
+    struct ScriptedMerkleTreeHook {
+        domain: HyperlaneDomain,
+        checkpoints: Mutex<VecDeque<Checkpoint>>,
+    }
+
+    impl HyperlaneChain for ScriptedMerkleTreeHook {
+        fn domain(&self) -> &HyperlaneDomain {
+            &self.domain
+        }
+
+        fn provider(&self) -> Box<dyn HyperlaneProvider> {
+            unimplemented!()
+        }
+    }
+
+    impl HyperlaneContract for ScriptedMerkleTreeHook {
+        fn address(&self) -> H256 {
+            H256::zero()
+        }
+    }
+
+    #[async_trait]
+    impl MerkleTreeHook for ScriptedMerkleTreeHook {
+        async fn tree(&self, _reorg_period: &ReorgPeriod) -> ChainResult<IncrementalMerkle> {
+            unimplemented!()
+        }
+
+        async fn count(&self, _reorg_period: &ReorgPeriod) -> ChainResult<u32> {
+            unimplemented!()
+        }
+
+        async fn latest_checkpoint(&self, _reorg_period: &ReorgPeriod) -> ChainResult<Checkpoint> {
+            let mut checkpoints = self.checkpoints.lock().unwrap();
+            if checkpoints.len() > 1 {
+                Ok(checkpoints.pop_front().unwrap())
+            } else {
+                Ok(*checkpoints.front().unwrap())
+            }
+        }
+    }
+
+    #[test]
+    fn watch_roots_deduplicates_consecutive_roots() {
+        let mut tree = IncrementalMerkle::default();
+        let mut checkpoints = VecDeque::new();
+        for leaf in 1..=3 {
+            tree.ingest(H256::from_low_u64_be(leaf));
+            let checkpoint = Checkpoint {
+                merkle_tree_hook_address: H256::zero(),
+                mailbox_domain: 1,
+                root: tree.root(),
+                index: tree.index(),
+            };
+            // each insertion is observed twice before the next one finalizes
+            checkpoints.push_back(checkpoint);
+            checkpoints.push_back(checkpoint);
+        }
+        let expected: Vec<_> = checkpoints
+            .iter()
+            .step_by(2)
+            .map(|c| (c.root, c.index))
+            .collect();
+
+        let merkle_tree_hook = Arc::new(ScriptedMerkleTreeHook {
+            domain: dummy_domain(1, "test"),
+            checkpoints: Mutex::new(checkpoints),
+        });
+        let t = async {
+            watch_roots(merkle_tree_hook, ReorgPeriod::None, None, Duration::ZERO)
+                .take(3)
+                .collect::<Vec<_>>()
+                .await
+        };
+        let roots = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(t);
+
+        assert_eq!(roots, expected);
+    }
+}
@@ -8,7 +8,7 @@ use serde::{
 };
 
 use crate::utils::bytes_to_hex;
-use crate::{Signature, H160, H256};
+use crate::{Signature, H160, H256, U256};
 
 /// An error incurred by a signer
 #[derive(thiserror::Error, Debug)]
@@ -125,6 +125,39 @@ impl<T: Signable> SignedType<T> {
     }
 }
 
+/// Half of the secp256k1 curve order. Signatures with a larger `s` value are
+/// malleable.
+#[cfg(feature = "ethers")]
+const SECP256K1_HALF_ORDER: U256 = U256([
+    0xDFE92F46681B20A0,
+    0x5D576E7357A4501D,
+    0xFFFFFFFFFFFFFFFF,
+    0x7FFFFFFFFFFFFFFF,
+]);
+
+/// Recover the Ethereum address that signed `digest`, e.g. a checkpoint's
+/// `eth_signed_message_hash`.
+///
+/// The signature must be 65 bytes of `r || s || v`, where `v` is either 27/28
+/// or 0/1. Malleable signatures with a high `s` value are rejected.
+#[cfg(feature = "ethers")]
+pub fn recover_signer(
+    digest: H256,
+    signature: &[u8],
+) -> Result<H160, crate::HyperlaneProtocolError> {
+    let mut sig = ethers_core::types::Signature::try_from(signature)?;
+    sig.v = match sig.v {
+        0 | 1 => sig.v + 27,
+        27 | 28 => sig.v,
+        v => return Err(crate::HyperlaneProtocolError::InvalidRecoveryId(v)),
+    };
+    if U256::from(sig.s) > SECP256K1_HALF_ORDER {
+        return Err(crate::HyperlaneProtocolError::MalleableSignature);
+    }
+    let digest = ethers_core::types::H256::from(digest);
+    Ok(sig.recover(digest)?.into())
+}
+
 impl<T: Signable + Debug> Debug for SignedType<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -184,3 +217,73 @@ mod hashes {
         );
     }
 }
+
+#[cfg(all(test, feature = "ethers"))]
+mod test {
+    use ethers_core::k256::ecdsa::SigningKey;
+    use ethers_core::utils::secret_key_to_address;
+
+    use super::{recover_signer, SECP256K1_HALF_ORDER};
+    use crate::{HyperlaneProtocolError, H160, H256, U256};
+
+    /// Sign `digest` and return the signer's address along with `r || s`
+    /// and the recovery ID (0 or 1).
+    fn sign(digest: H256) -> (H160, [u8; 64], u8) {
+        let key = SigningKey::from_bytes(&[0x11; 32].into()).unwrap();
+        let (signature, recovery_id) = key.sign_prehash_recoverable(digest.as_ref()).unwrap();
+        let mut rs = [0u8; 64];
+        rs.copy_from_slice(&signature.to_bytes());
+        let address = secret_key_to_address(&key).into();
+        (address, rs, recovery_id.to_byte())
+    }
+
+    fn with_v(rs: &[u8; 64], v: u8) -> Vec<u8> {
+        let mut signature = rs.to_vec();
+        signature.push(v);
+        signature
+    }
+
+    #[test]
+    fn recovers_signer_with_either_v_encoding() {
+        let digest = H256::repeat_byte(0xab);
+        let (address, rs, recovery_id) = sign(digest);
+
+        assert_eq!(
+            recover_signer(digest, &with_v(&rs, recovery_id)).unwrap(),
+            address
+        );
+        assert_eq!(
+            recover_signer(digest, &with_v(&rs, recovery_id + 27)).unwrap(),
+            address
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_recovery_id() {
+        let digest = H256::repeat_byte(0xab);
+        let (_, rs, _) = sign(digest);
+
+        assert!(matches!(
+            recover_signer(digest, &with_v(&rs, 2)),
+            Err(HyperlaneProtocolError::InvalidRecoveryId(2))
+        ));
+    }
+
+    #[test]
+    fn rejects_high_s_signature() {
+        let digest = H256::repeat_byte(0xab);
+        let (_, rs, recovery_id) = sign(digest);
+
+        // `n - s` with the flipped recovery ID is an equally valid signature
+        // of the same digest
+        let curve_order = SECP256K1_HALF_ORDER * 2 + 1;
+        let high_s = curve_order - U256::from_big_endian(&rs[32..]);
+        let mut malleated = rs;
+        high_s.to_big_endian(&mut malleated[32..]);
+
+        assert!(matches!(
+            recover_signer(digest, &with_v(&malleated, (recovery_id ^ 1) + 27)),
+            Err(HyperlaneProtocolError::MalleableSignature)
+        ));
+    }
+}
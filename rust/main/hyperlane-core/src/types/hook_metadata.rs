@@ -0,0 +1,149 @@
+use serde::Serialize;
+
+use crate::{Decode, Encode, HyperlaneProtocolError, H160, U256};
+
+const STANDARD_HOOK_METADATA_PREFIX_LEN: usize = 86;
+
+/// Metadata passed to post dispatch hooks that understand the standard
+/// layout, e.g. the IGP hook. Mirrors `StandardHookMetadata.sol`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize)]
+pub struct StandardHookMetadata {
+    /// 2   Metadata variant
+    pub variant: u16,
+    /// 32  `msg.value` for the message
+    pub msg_value: U256,
+    /// 32  Destination gas limit for the message (IGP)
+    pub gas_limit: U256,
+    /// 20  Refund address for overpayment (IGP)
+    pub refund_address: H160,
+    /// 0+  Hook specific metadata
+    pub custom_metadata: Vec<u8>,
+}
+
+impl StandardHookMetadata {
+    /// The only variant currently defined by the standard hooks
+    pub const VARIANT: u16 = 1;
+
+    /// Create metadata with the current variant and no custom metadata.
+    pub fn new(msg_value: U256, gas_limit: U256, refund_address: H160) -> Self {
+        Self {
+            variant: Self::VARIANT,
+            msg_value,
+            gas_limit,
+            refund_address,
+            custom_metadata: vec![],
+        }
+    }
+
+    /// Set the custom metadata appended after the standard fields.
+    pub fn with_custom_metadata(mut self, custom_metadata: Vec<u8>) -> Self {
+        self.custom_metadata = custom_metadata;
+        self
+    }
+}
+
+impl Encode for StandardHookMetadata {
+    fn write_to<W>(&self, writer: &mut W) -> std::io::Result<usize>
+    where
+        W: std::io::Write,
+    {
+        // `U256`'s `Encode` impl is little endian, the hooks expect big endian
+        let mut msg_value = [0u8; 32];
+        self.msg_value.to_big_endian(&mut msg_value);
+        let mut gas_limit = [0u8; 32];
+        self.gas_limit.to_big_endian(&mut gas_limit);
+
+        writer.write_all(&self.variant.to_be_bytes())?;
+        writer.write_all(&msg_value)?;
+        writer.write_all(&gas_limit)?;
+        writer.write_all(self.refund_address.as_ref())?;
+        writer.write_all(&self.custom_metadata)?;
+        Ok(STANDARD_HOOK_METADATA_PREFIX_LEN + self.custom_metadata.len())
+    }
+}
+
+impl Decode for StandardHookMetadata {
+    fn read_from<R>(reader: &mut R) -> Result<Self, HyperlaneProtocolError>
+    where
+        R: std::io::Read,
+    {
+        let mut variant = [0u8; 2];
+        reader.read_exact(&mut variant)?;
+
+        let mut msg_value = [0u8; 32];
+        reader.read_exact(&mut msg_value)?;
+
+        let mut gas_limit = [0u8; 32];
+        reader.read_exact(&mut gas_limit)?;
+
+        let mut refund_address = H160::zero();
+        reader.read_exact(refund_address.as_mut())?;
+
+        let mut custom_metadata = vec![];
+        reader.read_to_end(&mut custom_metadata)?;
+
+        Ok(Self {
+            variant: u16::from_be_bytes(variant),
+            msg_value: U256::from_big_endian(&msg_value),
+            gas_limit: U256::from_big_endian(&gas_limit),
+            refund_address,
+            custom_metadata,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    // `StandardHookMetadata.formatMetadata(1, 50_000, 0x1111...1111, "")`
+    const ENCODED: &str = concat!(
+        "0001",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "000000000000000000000000000000000000000000000000000000000000c350",
+        "1111111111111111111111111111111111111111",
+    );
+
+    fn refund_address() -> H160 {
+        H160::from_str("0x1111111111111111111111111111111111111111").unwrap()
+    }
+
+    #[test]
+    fn it_encodes_standard_layout() {
+        let metadata = StandardHookMetadata::new(1.into(), 50_000.into(), refund_address());
+        let encoded = metadata.to_vec();
+        assert_eq!(encoded.len(), STANDARD_HOOK_METADATA_PREFIX_LEN);
+        assert_eq!(hex::encode(&encoded), ENCODED);
+    }
+
+    #[test]
+    fn it_round_trips_custom_metadata() {
+        let metadata = StandardHookMetadata::new(0.into(), 300_000.into(), refund_address())
+            .with_custom_metadata(vec![0xde, 0xad, 0xbe, 0xef]);
+        let encoded = metadata.to_vec();
+        assert_eq!(hex::encode(&encoded[86..]), "deadbeef");
+        assert_eq!(
+            StandardHookMetadata::read_from(&mut encoded.as_slice()).unwrap(),
+            metadata
+        );
+    }
+
+    #[test]
+    fn it_decodes_known_encoding() {
+        let encoded = hex::decode(ENCODED).unwrap();
+        let metadata = StandardHookMetadata::read_from(&mut encoded.as_slice()).unwrap();
+        assert_eq!(metadata.variant, StandardHookMetadata::VARIANT);
+        assert_eq!(metadata.msg_value, 1.into());
+        assert_eq!(metadata.gas_limit, 50_000.into());
+        assert_eq!(metadata.refund_address, refund_address());
+        assert!(metadata.custom_metadata.is_empty());
+    }
+
+    #[test]
+    fn it_rejects_truncated_metadata() {
+        let encoded = hex::decode(ENCODED).unwrap();
+        assert!(StandardHookMetadata::read_from(&mut &encoded[..85]).is_err());
+    }
+}
@@ -18,6 +18,13 @@ impl HyperlaneIdentifier {
     pub fn as_ethereum_address(&self) -> H160 {
         H160::from_slice(&self.0.as_ref()[12..])
     }
+
+    /// Cast to an ethereum address, returning `None` if the first 12 bytes
+    /// are not all 0 and truncating would lose information.
+    pub fn try_as_ethereum_address(&self) -> Option<H160> {
+        self.is_ethereum_address()
+            .then(|| self.as_ethereum_address())
+    }
 }
 
 impl From<H256> for HyperlaneIdentifier {
@@ -76,3 +83,41 @@ impl Decode for HyperlaneIdentifier {
         Ok(HyperlaneIdentifier(H256::read_from(reader)?))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::utils::hex_or_base58_to_h256;
+
+    #[test]
+    fn it_round_trips_ethereum_addresses() {
+        let address = H160::from_str("0x1111111111111111111111111111111111111111").unwrap();
+        let id = HyperlaneIdentifier::from(address);
+        assert_eq!(
+            H256::from(id),
+            H256::from_str("0x0000000000000000000000001111111111111111111111111111111111111111")
+                .unwrap()
+        );
+        assert!(id.is_ethereum_address());
+        assert_eq!(id.try_as_ethereum_address(), Some(address));
+    }
+
+    #[test]
+    fn it_rejects_non_zero_padding() {
+        let id = HyperlaneIdentifier::from(H256::repeat_byte(0x11));
+        assert!(!id.is_ethereum_address());
+        assert_eq!(id.try_as_ethereum_address(), None);
+    }
+
+    #[test]
+    fn it_round_trips_base58_identifiers() {
+        let id = HyperlaneIdentifier::from(H256::repeat_byte(0x11));
+        let base58 = bs58::encode(id.as_ref()).into_string();
+        assert_eq!(
+            HyperlaneIdentifier::from(hex_or_base58_to_h256(&base58).unwrap()),
+            id
+        );
+    }
+}
@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "ethers")]
 use ethers_contract::LogMeta as EthersLogMeta;
 
+#[cfg(feature = "ethers")]
+use crate::{ChainCommunicationError, ChainResult};
 use crate::{H256, H512, U256};
 
 /// A close clone of the Ethereum `LogMeta`, this is designed to be a more
@@ -82,3 +84,78 @@ impl LogMeta {
         }
     }
 }
+
+#[cfg(feature = "ethers")]
+impl LogMeta {
+    /// Build the metadata of an ethers log. Unlike the `From<&Log>` conversion
+    /// of ethers' `LogMeta`, this errors instead of panicking if the log is
+    /// still pending and so lacks its block or transaction fields.
+    pub fn from_log(log: &ethers_core::types::Log) -> ChainResult<Self> {
+        let missing =
+            |field: &str| ChainCommunicationError::from_other_str(&format!("Log has no {field}"));
+        Ok(Self {
+            address: log.address.into(),
+            block_number: log
+                .block_number
+                .ok_or_else(|| missing("block number"))?
+                .as_u64(),
+            block_hash: log.block_hash.ok_or_else(|| missing("block hash"))?.into(),
+            transaction_id: log
+                .transaction_hash
+                .ok_or_else(|| missing("transaction hash"))?
+                .into(),
+            transaction_index: log
+                .transaction_index
+                .ok_or_else(|| missing("transaction index"))?
+                .as_u64(),
+            log_index: log.log_index.ok_or_else(|| missing("log index"))?.into(),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "ethers"))]
+mod test {
+    use ethers_core::types::{Log, H160 as EthersH160, H256 as EthersH256, U64};
+
+    use super::*;
+
+    fn mined_log() -> Log {
+        Log {
+            address: EthersH160::repeat_byte(1),
+            block_number: Some(U64::from(100)),
+            block_hash: Some(EthersH256::repeat_byte(2)),
+            transaction_hash: Some(EthersH256::repeat_byte(3)),
+            transaction_index: Some(U64::from(4)),
+            log_index: Some(5.into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_from_mined_log() {
+        let log_meta = LogMeta::from_log(&mined_log()).unwrap();
+        assert_eq!(
+            log_meta,
+            LogMeta {
+                address: H256::from(crate::H160::repeat_byte(1)),
+                block_number: 100,
+                block_hash: H256::repeat_byte(2),
+                transaction_id: H256::repeat_byte(3).into(),
+                transaction_index: 4,
+                log_index: U256::from(5),
+            }
+        );
+        // Matches the conversion through ethers' `LogMeta`
+        assert_eq!(log_meta, LogMeta::from(EthersLogMeta::from(&mined_log())));
+    }
+
+    #[test]
+    fn test_from_pending_log() {
+        let pending_log = Log {
+            block_number: None,
+            block_hash: None,
+            ..mined_log()
+        };
+        assert!(LogMeta::from_log(&pending_log).is_err());
+    }
+}
@@ -164,4 +164,95 @@ impl HyperlaneMessage {
     pub fn id(&self) -> H256 {
         H256::from_slice(Keccak256::new().chain(self.to_vec()).finalize().as_slice())
     }
+
+    /// Check that the message's origin matches the domain of the mailbox that
+    /// dispatched it
+    pub fn validate_origin(&self, expected_domain: u32) -> Result<(), HyperlaneProtocolError> {
+        if self.origin != expected_domain {
+            return Err(HyperlaneProtocolError::UnexpectedOrigin {
+                nonce: self.nonce,
+                origin: self.origin,
+                expected: expected_domain,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    // The message in `vectors/message.json`
+    const ENCODED: &str = concat!(
+        "03",
+        "00000000",
+        "000003e8",
+        "0000000000000000000000001111111111111111111111111111111111111111",
+        "000007d0",
+        "0000000000000000000000002222222222222222222222222222222222222222",
+        "1234",
+    );
+
+    #[test]
+    fn it_decodes_known_message() {
+        let message =
+            HyperlaneMessage::read_from(&mut hex::decode(ENCODED).unwrap().as_slice()).unwrap();
+
+        assert_eq!(message.version, 3);
+        assert_eq!(message.nonce, 0);
+        assert_eq!(message.origin, 1000);
+        assert_eq!(
+            message.sender,
+            H256::from_str("0x0000000000000000000000001111111111111111111111111111111111111111")
+                .unwrap()
+        );
+        assert_eq!(message.destination, 2000);
+        assert_eq!(
+            message.recipient,
+            H256::from_str("0x0000000000000000000000002222222222222222222222222222222222222222")
+                .unwrap()
+        );
+        assert_eq!(message.body, vec![0x12, 0x34]);
+        assert_eq!(
+            message.id(),
+            H256::from_str("0xf8a66f8aadee751d842616fee0ed14a3ad6da1e13564920364ee0ad35a02703f")
+                .unwrap()
+        );
+        assert_eq!(hex::encode(message.to_vec()), ENCODED);
+    }
+
+    #[test]
+    fn it_validates_origin() {
+        let message = HyperlaneMessage {
+            nonce: 7,
+            origin: 1000,
+            ..Default::default()
+        };
+        assert!(message.validate_origin(1000).is_ok());
+
+        let err = message.validate_origin(2000).unwrap_err();
+        assert!(matches!(
+            err,
+            HyperlaneProtocolError::UnexpectedOrigin {
+                nonce: 7,
+                origin: 1000,
+                expected: 2000
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Message with nonce 7 has origin 1000, expected 2000"
+        );
+    }
+
+    #[test]
+    fn it_rejects_truncated_message() {
+        let encoded = hex::decode(ENCODED).unwrap();
+        assert!(
+            HyperlaneMessage::read_from(&mut &encoded[..HYPERLANE_MESSAGE_PREFIX_LEN - 1]).is_err()
+        );
+    }
 }
@@ -13,6 +13,7 @@ pub use block_id::BlockId;
 pub use chain_data::*;
 pub use checkpoint::*;
 pub use conversions::*;
+pub use hook_metadata::*;
 pub use indexing::*;
 pub use log_metadata::*;
 pub use merkle_tree::*;
@@ -30,6 +31,7 @@ mod block_id;
 mod chain_data;
 mod checkpoint;
 mod conversions;
+mod hook_metadata;
 mod indexing;
 mod log_metadata;
 mod merkle_tree;
@@ -35,6 +35,10 @@ mock! {
         pub fn _default_ism(&self) -> ChainResult<H256> {}
         pub fn _recipient_ism(&self, recipient: H256) -> ChainResult<H256> {}
 
+        pub fn _default_hook(&self) -> ChainResult<H256> {}
+        pub fn _required_hook(&self) -> ChainResult<H256> {}
+        pub fn _owner(&self) -> ChainResult<H256> {}
+
         pub fn _delivered(&self, id: H256) -> ChainResult<bool> {}
 
         pub fn process(
@@ -82,6 +86,18 @@ impl Mailbox for MockMailboxContract {
         self._delivered(id)
     }
 
+    async fn default_hook(&self) -> ChainResult<H256> {
+        self._default_hook()
+    }
+
+    async fn required_hook(&self) -> ChainResult<H256> {
+        self._required_hook()
+    }
+
+    async fn owner(&self) -> ChainResult<H256> {
+        self._owner()
+    }
+
     async fn process(
         &self,
         message: &HyperlaneMessage,
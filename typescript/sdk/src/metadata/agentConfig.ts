@@ -145,6 +145,12 @@ export const AgentChainMetadataSchema = ChainMetadataSchemaObject.merge(
         chunk: ZNzUint.optional().describe(
           'The number of blocks to index at a time.',
         ),
+        minChunk: ZNzUint.optional().describe(
+          'The fewest blocks to index at a time after the RPC rejects a range as too large.',
+        ),
+        maxChunk: ZNzUint.optional().describe(
+          'The most blocks to index at a time after growing the range on a quiet chain. Defaults to the chunk size, so the range only grows if this is set.',
+        ),
         mode: z
           .nativeEnum(AgentIndexMode)
           .optional()
@@ -318,6 +324,9 @@ export const RelayerAgentConfigSchema = AgentConfigSchema.extend({
     .string()
     .optional()
     .describe('Comma separated list of addresses to blacklist.'),
+  maxMessageBodyBytes: ZNzUint.optional().describe(
+    'This is optional. If specified, messages with a larger body in bytes are not relayed.',
+  ),
   transactionGasLimit: ZUWei.optional().describe(
     'This is optional. If not specified, any amount of gas will be valid, otherwise this is the max allowed gas in wei to relay a transaction.',
   ),